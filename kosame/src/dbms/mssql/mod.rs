@@ -1,3 +1,11 @@
+use std::fmt::Write;
+
+use crate::{
+    query::BindParamOrdinal,
+    schema::{Column, Generated},
+    sql::{Formatter, IndexMethodPosition},
+};
+
 pub enum Dialect {}
 
 impl crate::sql::Dialect for Dialect {
@@ -12,4 +20,36 @@ impl crate::sql::Dialect for Dialect {
     ) -> std::fmt::Result {
         write!(formatter, "@{name}")
     }
+
+    fn fmt_column_type(formatter: &mut Formatter<'_, Self>, column: &Column) -> std::fmt::Result {
+        // Computed columns take the type of their expression and cannot declare one.
+        match column.generated() {
+            Some(Generated::Stored { expr }) => {
+                formatter.write_str(" as (")?;
+                expr.fmt_sql(formatter)?;
+                formatter.write_str(") persisted")
+            }
+            Some(Generated::Identity { .. }) => {
+                formatter.write_str(" ")?;
+                column.data_type().fmt_sql(formatter)?;
+                formatter.write_str(" identity(1, 1)")
+            }
+            None => {
+                formatter.write_str(" ")?;
+                column.data_type().fmt_sql(formatter)
+            }
+        }
+    }
+
+    fn anonymous_indexes() -> bool {
+        false
+    }
+
+    fn index_method_position() -> Option<IndexMethodPosition> {
+        None
+    }
+
+    fn alter_table_add_constraint() -> bool {
+        true
+    }
 }
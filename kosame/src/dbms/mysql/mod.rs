@@ -1,3 +1,11 @@
+use std::fmt::Write;
+
+use crate::{
+    query::BindParamOrdinal,
+    schema::{Column, Generated},
+    sql::{Formatter, IndexMethodPosition},
+};
+
 pub enum Dialect {}
 
 impl crate::sql::Dialect for Dialect {
@@ -12,4 +20,26 @@ impl crate::sql::Dialect for Dialect {
     ) -> std::fmt::Result {
         write!(formatter, ":{name}")
     }
+
+    fn fmt_column_type(formatter: &mut Formatter<'_, Self>, column: &Column) -> std::fmt::Result {
+        formatter.write_str(" ")?;
+        column.data_type().fmt_sql(formatter)?;
+        match column.generated() {
+            Some(Generated::Identity { .. }) => formatter.write_str(" auto_increment"),
+            Some(generated) => generated.fmt_stored_sql(formatter),
+            None => Ok(()),
+        }
+    }
+
+    fn anonymous_indexes() -> bool {
+        false
+    }
+
+    fn index_method_position() -> Option<IndexMethodPosition> {
+        Some(IndexMethodPosition::AfterColumns)
+    }
+
+    fn alter_table_add_constraint() -> bool {
+        true
+    }
 }
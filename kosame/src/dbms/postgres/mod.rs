@@ -1,6 +1,10 @@
 use std::fmt::Write;
 
-use crate::query::BindParamOrdinal;
+use crate::{
+    query::BindParamOrdinal,
+    schema::{Column, Generated},
+    sql::{Formatter, IndexMethodPosition},
+};
 
 pub enum Dialect {}
 
@@ -16,4 +20,39 @@ impl crate::sql::Dialect for Dialect {
     ) -> std::fmt::Result {
         write!(formatter, "${}", ordinal + 1)
     }
+
+    fn fmt_column_type(formatter: &mut Formatter<'_, Self>, column: &Column) -> std::fmt::Result {
+        // A bare `char` is the single-byte `"char"` type, so its name is quoted to keep the
+        // database from creating a `character(1)` instead.
+        let data_type = column.data_type();
+        formatter.write_str(" ")?;
+        if data_type.name() == "char" && data_type.params().is_empty() {
+            formatter.write_ident(data_type.name())?;
+            data_type.fmt_modifiers(formatter)?;
+        } else {
+            data_type.fmt_sql(formatter)?;
+        }
+        match column.generated() {
+            Some(Generated::Identity { always: true }) => {
+                formatter.write_str(" generated always as identity")
+            }
+            Some(Generated::Identity { always: false }) => {
+                formatter.write_str(" generated by default as identity")
+            }
+            Some(generated) => generated.fmt_stored_sql(formatter),
+            None => Ok(()),
+        }
+    }
+
+    fn anonymous_indexes() -> bool {
+        true
+    }
+
+    fn index_method_position() -> Option<IndexMethodPosition> {
+        Some(IndexMethodPosition::BeforeColumns)
+    }
+
+    fn alter_table_add_constraint() -> bool {
+        true
+    }
 }
@@ -1,3 +1,11 @@
+use std::fmt::Write;
+
+use crate::{
+    query::BindParamOrdinal,
+    schema::{Column, Generated},
+    sql::{Formatter, IndexMethodPosition},
+};
+
 pub enum Dialect {}
 
 impl crate::sql::Dialect for Dialect {
@@ -12,4 +20,27 @@ impl crate::sql::Dialect for Dialect {
     ) -> std::fmt::Result {
         write!(formatter, ":{name}")
     }
+
+    fn fmt_column_type(formatter: &mut Formatter<'_, Self>, column: &Column) -> std::fmt::Result {
+        // SQLite has no identity columns. An `integer primary key` column is filled in
+        // automatically instead.
+        formatter.write_str(" ")?;
+        column.data_type().fmt_sql(formatter)?;
+        match column.generated() {
+            Some(Generated::Identity { .. }) | None => Ok(()),
+            Some(generated) => generated.fmt_stored_sql(formatter),
+        }
+    }
+
+    fn anonymous_indexes() -> bool {
+        false
+    }
+
+    fn index_method_position() -> Option<IndexMethodPosition> {
+        None
+    }
+
+    fn alter_table_add_constraint() -> bool {
+        false
+    }
 }
@@ -21,4 +21,64 @@ pub trait Connection {
         sql: &str,
         params: &Self::Params<'_>,
     ) -> impl Future<Output = Result<Vec<Self::Row>, Self::Error>> + Send;
+
+    fn execute(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<u64, Self::Error>> + Send;
+
+    /// The schema used to qualify tables that are declared without one.
+    fn schema(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// A connection that qualifies every table declared without an explicit schema with the given
+/// schema, e.g. to run the same statements against the tables of different tenants.
+///
+/// The schema is escaped like any other identifier, so it may safely come from user input.
+pub struct WithSchema<'a, C> {
+    connection: &'a mut C,
+    schema: &'a str,
+}
+
+impl<'a, C> WithSchema<'a, C> {
+    pub fn new(connection: &'a mut C, schema: &'a str) -> Self {
+        Self { connection, schema }
+    }
+
+    pub fn connection(&mut self) -> &mut C {
+        self.connection
+    }
+}
+
+impl<C> Connection for WithSchema<'_, C>
+where
+    C: Connection,
+{
+    type Dialect = C::Dialect;
+    type Params<'a> = C::Params<'a>;
+    type Row = C::Row;
+    type Error = C::Error;
+
+    fn query(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<Vec<Self::Row>, Self::Error>> + Send {
+        self.connection.query(sql, params)
+    }
+
+    fn execute(
+        &mut self,
+        sql: &str,
+        params: &Self::Params<'_>,
+    ) -> impl Future<Output = Result<u64, Self::Error>> + Send {
+        self.connection.execute(sql, params)
+    }
+
+    fn schema(&self) -> Option<&str> {
+        Some(self.schema)
+    }
 }
@@ -13,6 +13,10 @@ impl Connection for postgres::Client {
     ) -> Result<Vec<Self::Row>, Self::Error> {
         postgres::Client::query(self, sql, params)
     }
+
+    async fn execute(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
+        postgres::Client::execute(self, sql, params)
+    }
 }
 
 impl Connection for postgres::Transaction<'_> {
@@ -28,4 +32,8 @@ impl Connection for postgres::Transaction<'_> {
     ) -> Result<Vec<Self::Row>, Self::Error> {
         postgres::Transaction::<'_>::query(self, sql, params)
     }
+
+    async fn execute(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
+        postgres::Transaction::<'_>::execute(self, sql, params)
+    }
 }
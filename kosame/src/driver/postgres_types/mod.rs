@@ -1,6 +1,7 @@
+pub use bytes::BytesMut;
 use fallible_iterator::FallibleIterator;
 pub use postgres_protocol::types::int4_from_sql;
-pub use postgres_types::{FromSql, ToSql, Type};
+pub use postgres_types::{FromSql, IsNull, Kind, ToSql, Type, to_sql_checked};
 pub use tokio_postgres::Row;
 
 impl<'a, T> FromSql<'a> for crate::relation::OneToMany<T>
@@ -84,6 +85,116 @@ where
     }
 }
 
+impl<'a> crate::params::Params<Vec<&'a (dyn ToSql + Sync + 'a)>> for () {
+    fn to_driver(&self) -> Vec<&'a (dyn ToSql + Sync + 'a)> {
+        vec![]
+    }
+}
+
+impl<'a> FromSql<'a> for crate::types::Interval {
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let raw: [u8; 16] = raw.try_into().map_err(|_| "invalid interval length")?;
+        Ok(Self {
+            microseconds: i64::from_be_bytes(raw[0..8].try_into().unwrap()),
+            days: i32::from_be_bytes(raw[8..12].try_into().unwrap()),
+            months: i32::from_be_bytes(raw[12..16].try_into().unwrap()),
+        })
+    }
+}
+
+impl ToSql for crate::types::Interval {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        out.extend_from_slice(&self.microseconds.to_be_bytes());
+        out.extend_from_slice(&self.days.to_be_bytes());
+        out.extend_from_slice(&self.months.to_be_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    to_sql_checked!();
+}
+
+#[cfg(feature = "serde_json")]
+impl<'a, T> FromSql<'a> for crate::types::Json<T>
+where
+    T: serde::Deserialize<'a>,
+{
+    fn accepts(ty: &Type) -> bool {
+        <postgres_types::Json<T> as FromSql>::accepts(ty)
+    }
+
+    fn from_sql(
+        ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        postgres_types::Json::<T>::from_sql(ty, raw).map(|json| Self(json.0))
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> ToSql for crate::types::Json<T>
+where
+    T: serde::Serialize + std::fmt::Debug,
+{
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        postgres_types::Json(&self.0).to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <postgres_types::Json<T> as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl TryFrom<&Row> for crate::schema::CatalogColumn {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            schema: row.try_get(0)?,
+            table: row.try_get(1)?,
+            current_schema: row.try_get(2)?,
+            column: row.try_get(3)?,
+            nullable: row.try_get(4)?,
+            data_type: row.try_get(5)?,
+        })
+    }
+}
+
+impl TryFrom<&Row> for crate::schema::CatalogForeignKey {
+    type Error = tokio_postgres::Error;
+
+    fn try_from(row: &Row) -> Result<Self, Self::Error> {
+        Ok(Self {
+            schema: row.try_get(0)?,
+            table: row.try_get(1)?,
+            columns: row.try_get(2)?,
+            target_schema: row.try_get(3)?,
+            target_table: row.try_get(4)?,
+            target_columns: row.try_get(5)?,
+        })
+    }
+}
+
 pub fn record_field_from_sql<'a, T>(
     buf: &'a [u8],
     offset: &mut usize,
@@ -91,20 +202,140 @@ pub fn record_field_from_sql<'a, T>(
 where
     T: FromSql<'a>,
 {
-    let oid = postgres_protocol::types::oid_from_sql(&buf[*offset..(*offset + 4)])? as u32;
-    *offset += 4;
-    let Some(ty) = ::postgres_types::Type::from_oid(oid) else {
-        panic!("unknown oid {}", oid);
-    };
-    let length = postgres_protocol::types::int4_from_sql(&buf[*offset..(*offset + 4)])?;
-    *offset += 4;
+    let header = buf
+        .get(*offset..*offset + 8)
+        .ok_or("unexpected end of record")?;
+    let oid = postgres_protocol::types::oid_from_sql(&header[..4])?;
+    let length = postgres_protocol::types::int4_from_sql(&header[4..])?;
+    *offset += 8;
 
-    if length < 0 {
-        Ok(T::from_sql_null(&ty)?)
+    let value = if length < 0 {
+        None
     } else {
-        let length = length as usize;
-        let result = Ok(T::from_sql(&ty, &buf[(*offset)..(*offset + length)])?);
-        *offset += length;
-        result
+        let value = buf
+            .get(*offset..*offset + length as usize)
+            .ok_or("unexpected end of record")?;
+        *offset += length as usize;
+        Some(value)
+    };
+
+    // Records only carry the OID of each field. User-defined types, like enums, have no well-known
+    // OID, so they are decoded using a placeholder type instead.
+    let ty = match ::postgres_types::Type::from_oid(oid) {
+        Some(ty) => ty,
+        None => placeholder_type(oid, value.unwrap_or_default()),
+    };
+
+    match value {
+        Some(value) => Ok(T::from_sql(&ty, value)?),
+        None => Ok(T::from_sql_null(&ty)?),
+    }
+}
+
+pub fn record_field_to_sql<T>(
+    value: &T,
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<(), Box<dyn std::error::Error + Sync + Send>>
+where
+    T: ToSql,
+{
+    out.extend_from_slice(&ty.oid().to_be_bytes());
+    let length_offset = out.len();
+    out.extend_from_slice(&[0; 4]);
+    let length = match value.to_sql(ty, out)? {
+        IsNull::Yes => -1,
+        IsNull::No => i32::try_from(out.len() - length_offset - 4)?,
+    };
+    out[length_offset..(length_offset + 4)].copy_from_slice(&length.to_be_bytes());
+    Ok(())
+}
+
+/// Builds a type for an unknown OID. Arrays of user-defined types are recognized by their header,
+/// because the driver refuses to decode an array whose type is not of the array kind. The element
+/// type is taken from the header, so that `Vec<T>` decodes each element using the type of its
+/// `Kind::Array`.
+fn placeholder_type(oid: u32, raw: &[u8]) -> Type {
+    let kind = match array_element_type(raw) {
+        Some(element_type) => Kind::Array(
+            Type::from_oid(element_type).unwrap_or_else(|| placeholder_type(element_type, &[])),
+        ),
+        None => Kind::Pseudo,
+    };
+    Type::new(String::new(), oid, kind, String::new())
+}
+
+/// Returns the element type of an array value, or `None` if the value is not an array. A value
+/// only counts as an array if its header and elements account for every byte of it, so that
+/// values of other types whose first bytes happen to look like an array header are not
+/// mistaken for one.
+fn array_element_type(raw: &[u8]) -> Option<u32> {
+    let array = postgres_protocol::types::array_from_sql(raw).ok()?;
+    let dimensions = array.dimensions().count().ok()?;
+    let mut length = 12 + 8 * dimensions;
+    let mut values = array.values();
+    while let Some(value) = values.next().ok()? {
+        length += 4 + value.map_or(0, <[u8]>::len);
+    }
+    (length == raw.len()).then(|| array.element_type())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_field_round_trip() {
+        let mut buf = BytesMut::new();
+        record_field_to_sql(&42i32, &Type::INT4, &mut buf).unwrap();
+        record_field_to_sql(&None::<String>, &Type::TEXT, &mut buf).unwrap();
+        record_field_to_sql(&"hello", &Type::TEXT, &mut buf).unwrap();
+
+        let mut offset = 0;
+        let number: i32 = record_field_from_sql(&buf, &mut offset).unwrap();
+        let null: Option<String> = record_field_from_sql(&buf, &mut offset).unwrap();
+        let text: String = record_field_from_sql(&buf, &mut offset).unwrap();
+        assert_eq!(number, 42);
+        assert_eq!(null, None);
+        assert_eq!(text, "hello");
+        assert_eq!(offset, buf.len());
+    }
+
+    #[test]
+    fn record_field_truncated() {
+        let mut buf = BytesMut::new();
+        record_field_to_sql(&"hello", &Type::TEXT, &mut buf).unwrap();
+
+        // Cut off inside the header and inside the value.
+        for length in [0, 6, buf.len() - 1] {
+            let mut offset = 0;
+            let error = record_field_from_sql::<String>(&buf[..length], &mut offset).unwrap_err();
+            assert_eq!(error.to_string(), "unexpected end of record");
+        }
+    }
+
+    #[test]
+    fn record_field_null_into_non_nullable() {
+        let mut buf = BytesMut::new();
+        record_field_to_sql(&None::<i32>, &Type::INT4, &mut buf).unwrap();
+
+        let mut offset = 0;
+        assert!(record_field_from_sql::<i32>(&buf, &mut offset).is_err());
+    }
+
+    #[test]
+    fn array_element_type_of_values() {
+        let mut array = BytesMut::new();
+        vec![Some(1i32), None]
+            .to_sql(&Type::INT4_ARRAY, &mut array)
+            .unwrap();
+        assert_eq!(array_element_type(&array), Some(Type::INT4.oid()));
+
+        // Trailing or missing bytes mean that the value is not an array.
+        let mut trailing = array.clone();
+        trailing.extend_from_slice(&[0]);
+        assert_eq!(array_element_type(&trailing), None);
+        assert_eq!(array_element_type(&array[..array.len() - 1]), None);
+        assert_eq!(array_element_type(b"hello"), None);
     }
 }
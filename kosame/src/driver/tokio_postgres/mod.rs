@@ -13,6 +13,10 @@ impl Connection for tokio_postgres::Client {
     ) -> Result<Vec<Self::Row>, Self::Error> {
         tokio_postgres::Client::query(self, sql, params).await
     }
+
+    async fn execute(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
+        tokio_postgres::Client::execute(self, sql, params).await
+    }
 }
 
 impl Connection for tokio_postgres::Transaction<'_> {
@@ -28,4 +32,8 @@ impl Connection for tokio_postgres::Transaction<'_> {
     ) -> Result<Vec<Self::Row>, Self::Error> {
         tokio_postgres::Transaction::<'_>::query(self, sql, params).await
     }
+
+    async fn execute(&mut self, sql: &str, params: &Self::Params<'_>) -> Result<u64, Self::Error> {
+        tokio_postgres::Transaction::<'_>::execute(self, sql, params).await
+    }
 }
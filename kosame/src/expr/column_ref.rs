@@ -1,15 +1,25 @@
+use std::fmt::Write;
+
 use crate::{schema::Column, sql};
 
 pub struct ColumnRef {
+    correlation: Option<&'static str>,
     column: &'static Column,
 }
 
 impl ColumnRef {
-    pub const fn new(column: &'static Column) -> Self {
-        Self { column }
+    pub const fn new(correlation: Option<&'static str>, column: &'static Column) -> Self {
+        Self {
+            correlation,
+            column,
+        }
     }
 
     pub fn fmt_sql<D: sql::Dialect>(&self, formatter: &mut sql::Formatter<D>) -> std::fmt::Result {
+        if let Some(correlation) = self.correlation {
+            formatter.write_ident(correlation)?;
+            formatter.write_str(".")?;
+        }
         formatter.write_ident(self.column.name())
     }
 }
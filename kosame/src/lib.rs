@@ -2,12 +2,25 @@ mod dbms;
 pub mod driver;
 mod error;
 pub mod expr;
+pub mod mutation;
 pub mod params;
 pub mod query;
 pub mod relation;
 pub mod schema;
 pub mod sql;
+pub mod types;
 
 pub use dbms::*;
 pub use error::*;
 pub use kosame_macro::*;
+
+#[cfg(feature = "chrono")]
+pub use chrono;
+#[cfg(feature = "rust_decimal")]
+pub use rust_decimal;
+#[cfg(feature = "serde_json")]
+pub use serde_json;
+#[cfg(feature = "time")]
+pub use time;
+#[cfg(feature = "uuid")]
+pub use uuid;
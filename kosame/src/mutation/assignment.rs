@@ -0,0 +1,28 @@
+use std::fmt::Write;
+
+use super::*;
+
+pub struct Assignment {
+    column: &'static Column,
+    expr: Expr,
+}
+
+impl Assignment {
+    pub const fn new(column: &'static Column, expr: Expr) -> Self {
+        Self { column, expr }
+    }
+
+    pub const fn column(&self) -> &'static Column {
+        self.column
+    }
+
+    pub const fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    pub fn fmt_sql<D: sql::Dialect>(&self, formatter: &mut sql::Formatter<D>) -> std::fmt::Result {
+        formatter.write_ident(self.column.name())?;
+        formatter.write_str(" = ")?;
+        self.expr.fmt_sql(formatter)
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt::Write;
+
+use super::*;
+
+pub struct Delete {
+    table: &'static Table,
+    filter: Option<Expr>,
+    returning: Option<Returning>,
+}
+
+impl Delete {
+    pub const fn new(
+        table: &'static Table,
+        filter: Option<Expr>,
+        returning: Option<Returning>,
+    ) -> Self {
+        Self {
+            table,
+            filter,
+            returning,
+        }
+    }
+
+    pub const fn table(&self) -> &'static Table {
+        self.table
+    }
+
+    pub const fn filter(&self) -> Option<&Expr> {
+        self.filter.as_ref()
+    }
+
+    pub const fn returning(&self) -> Option<&Returning> {
+        self.returning.as_ref()
+    }
+
+    pub fn fmt_sql<D: sql::Dialect>(&self, formatter: &mut sql::Formatter<D>) -> std::fmt::Result {
+        formatter.write_str("delete from ")?;
+        formatter.write_table_ident(self.table)?;
+
+        if let Some(filter) = &self.filter {
+            formatter.write_str(" where ")?;
+            filter.fmt_sql(formatter)?;
+        }
+
+        if let Some(returning) = &self.returning {
+            returning.fmt_sql(formatter, self.table)?;
+        }
+
+        Ok(())
+    }
+}
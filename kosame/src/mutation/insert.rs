@@ -0,0 +1,236 @@
+use std::fmt::Write;
+
+use super::*;
+
+pub struct Insert {
+    table: &'static Table,
+    columns: &'static [&'static Column],
+    values: &'static [&'static [Expr]],
+    on_conflict: Option<OnConflict>,
+    nested: &'static [NestedInsert],
+    returning: Option<Returning>,
+}
+
+impl Insert {
+    pub const fn new(
+        table: &'static Table,
+        columns: &'static [&'static Column],
+        values: &'static [&'static [Expr]],
+        on_conflict: Option<OnConflict>,
+        nested: &'static [NestedInsert],
+        returning: Option<Returning>,
+    ) -> Self {
+        Self {
+            table,
+            columns,
+            values,
+            on_conflict,
+            nested,
+            returning,
+        }
+    }
+
+    pub const fn table(&self) -> &'static Table {
+        self.table
+    }
+
+    pub const fn columns(&self) -> &'static [&'static Column] {
+        self.columns
+    }
+
+    pub const fn values(&self) -> &'static [&'static [Expr]] {
+        self.values
+    }
+
+    pub const fn on_conflict(&self) -> Option<&OnConflict> {
+        self.on_conflict.as_ref()
+    }
+
+    pub const fn nested(&self) -> &'static [NestedInsert] {
+        self.nested
+    }
+
+    pub const fn returning(&self) -> Option<&Returning> {
+        self.returning.as_ref()
+    }
+
+    pub fn fmt_sql<D: sql::Dialect>(&self, formatter: &mut sql::Formatter<D>) -> std::fmt::Result {
+        if self.nested.is_empty() {
+            self.fmt_insert_sql(formatter)?;
+            if let Some(returning) = &self.returning {
+                returning.fmt_sql(formatter, self.table)?;
+            }
+            return Ok(());
+        }
+
+        // Nested inserts are chained through data-modifying common table expressions, so that
+        // child rows can read the generated keys of their parent row.
+        let name = "insert";
+        formatter.write_str("with ")?;
+        formatter.write_ident(name)?;
+        formatter.write_str(" as (")?;
+        self.fmt_insert_sql(formatter)?;
+        formatter.write_str(" returning *)")?;
+
+        for (index, nested) in self.nested.iter().enumerate() {
+            nested.fmt_sql(formatter, name, index)?;
+        }
+
+        formatter.write_str(" select")?;
+        if let Some(returning) = &self.returning {
+            formatter.write_str(" ")?;
+            returning.fmt_fields_sql(formatter, self.table)?;
+        }
+        formatter.write_str(" from ")?;
+        formatter.write_ident(name)?;
+        formatter.write_str(" as ")?;
+        formatter.write_ident(self.table.name())?;
+
+        Ok(())
+    }
+
+    fn fmt_insert_sql<D: sql::Dialect>(
+        &self,
+        formatter: &mut sql::Formatter<D>,
+    ) -> std::fmt::Result {
+        formatter.write_str("insert into ")?;
+        formatter.write_table_ident(self.table)?;
+
+        formatter.write_str(" (")?;
+        for (index, column) in self.columns.iter().enumerate() {
+            formatter.write_ident(column.name())?;
+            if index != self.columns.len() - 1 {
+                formatter.write_str(", ")?;
+            }
+        }
+        formatter.write_str(")")?;
+
+        formatter.write_str(" values ")?;
+        fmt_values_sql(formatter, self.values, |_| Ok(()))?;
+
+        if let Some(on_conflict) = &self.on_conflict {
+            on_conflict.fmt_sql(formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct NestedInsert {
+    relation: &'static Relation,
+    table: &'static Table,
+    columns: &'static [&'static Column],
+    values: &'static [&'static [Expr]],
+    nested: &'static [NestedInsert],
+}
+
+impl NestedInsert {
+    pub const fn new(
+        relation: &'static Relation,
+        table: &'static Table,
+        columns: &'static [&'static Column],
+        values: &'static [&'static [Expr]],
+        nested: &'static [NestedInsert],
+    ) -> Self {
+        Self {
+            relation,
+            table,
+            columns,
+            values,
+            nested,
+        }
+    }
+
+    pub const fn relation(&self) -> &'static Relation {
+        self.relation
+    }
+
+    pub const fn table(&self) -> &'static Table {
+        self.table
+    }
+
+    pub const fn columns(&self) -> &'static [&'static Column] {
+        self.columns
+    }
+
+    pub const fn values(&self) -> &'static [&'static [Expr]] {
+        self.values
+    }
+
+    pub const fn nested(&self) -> &'static [NestedInsert] {
+        self.nested
+    }
+
+    fn fmt_sql<D: sql::Dialect>(
+        &self,
+        formatter: &mut sql::Formatter<D>,
+        parent: &str,
+        index: usize,
+    ) -> std::fmt::Result {
+        let name = format!("{parent}_{index}");
+
+        formatter.write_str(", ")?;
+        formatter.write_ident(&name)?;
+        formatter.write_str(" as (insert into ")?;
+        formatter.write_table_ident(self.table)?;
+
+        formatter.write_str(" (")?;
+        for column in self.relation.target_columns() {
+            formatter.write_ident(column.name())?;
+            formatter.write_str(", ")?;
+        }
+        for (index, column) in self.columns.iter().enumerate() {
+            formatter.write_ident(column.name())?;
+            if index != self.columns.len() - 1 {
+                formatter.write_str(", ")?;
+            }
+        }
+        formatter.write_str(")")?;
+
+        formatter.write_str(" values ")?;
+        fmt_values_sql(formatter, self.values, |formatter| {
+            for column in self.relation.source_columns() {
+                formatter.write_str("(select ")?;
+                formatter.write_ident(column.name())?;
+                formatter.write_str(" from ")?;
+                formatter.write_ident(parent)?;
+                formatter.write_str("), ")?;
+            }
+            Ok(())
+        })?;
+
+        if !self.nested.is_empty() {
+            formatter.write_str(" returning *")?;
+        }
+        formatter.write_str(")")?;
+
+        for (index, nested) in self.nested.iter().enumerate() {
+            nested.fmt_sql(formatter, &name, index)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn fmt_values_sql<D: sql::Dialect>(
+    formatter: &mut sql::Formatter<D>,
+    values: &[&[Expr]],
+    mut prefix: impl FnMut(&mut sql::Formatter<D>) -> std::fmt::Result,
+) -> std::fmt::Result {
+    for (index, row) in values.iter().enumerate() {
+        formatter.write_str("(")?;
+        prefix(formatter)?;
+        for (index, expr) in row.iter().enumerate() {
+            expr.fmt_sql(formatter)?;
+            if index != row.len() - 1 {
+                formatter.write_str(", ")?;
+            }
+        }
+        formatter.write_str(")")?;
+        if index != values.len() - 1 {
+            formatter.write_str(", ")?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+mod assignment;
+mod delete;
+mod insert;
+mod on_conflict;
+mod record;
+mod refresh;
+mod returning;
+mod statement;
+mod update;
+
+pub use assignment::*;
+pub use delete::*;
+pub use insert::*;
+pub use on_conflict::*;
+pub use record::*;
+pub use refresh::*;
+pub use returning::*;
+pub use statement::*;
+pub use update::*;
+
+use crate::{
+    Error,
+    driver::Connection,
+    expr::Expr,
+    params::Params,
+    schema::{Column, Relation, Table},
+    sql,
+};
+use pollster::FutureExt;
+
+pub trait Mutation {
+    type Params: std::fmt::Debug;
+    type Row;
+
+    const STATEMENT: Statement;
+
+    fn statement(&self) -> &'static Statement {
+        &Self::STATEMENT
+    }
+
+    fn params(&self) -> &Self::Params;
+
+    fn to_sql<D: sql::Dialect>(&self) -> String {
+        sql::to_sql_string::<D>(None, |formatter| self.statement().fmt_sql(formatter))
+    }
+
+    fn exec<'c, C>(&self, connection: &mut C) -> impl Future<Output = Result<u64, Error<C>>>
+    where
+        C: Connection,
+        Self::Params: Params<C::Params<'c>>,
+    {
+        async {
+            let sql = sql::to_sql_string::<C::Dialect>(connection.schema(), |formatter| {
+                self.statement().fmt_sql(formatter)
+            });
+            match connection.execute(&sql, &self.params().to_driver()).await {
+                Ok(row_count) => Ok(row_count),
+                Err(error) => Err(Error::Connection(error)),
+            }
+        }
+    }
+
+    fn exec_sync<'c, C>(&self, connection: &mut C) -> Result<u64, Error<C>>
+    where
+        C: Connection,
+        Self::Params: Params<C::Params<'c>>,
+    {
+        self.exec(connection).block_on()
+    }
+
+    fn exec_returning<'c, C>(
+        &self,
+        connection: &mut C,
+    ) -> impl Future<Output = Result<Vec<Self::Row>, Error<C>>>
+    where
+        C: Connection,
+        Self::Params: Params<C::Params<'c>>,
+        for<'b> Self::Row: From<&'b C::Row>,
+    {
+        async {
+            let sql = sql::to_sql_string::<C::Dialect>(connection.schema(), |formatter| {
+                self.statement().fmt_sql(formatter)
+            });
+            let rows = match connection.query(&sql, &self.params().to_driver()).await {
+                Ok(rows) => rows,
+                Err(error) => return Err(Error::Connection(error)),
+            };
+            Ok(rows.iter().map(Self::Row::from).collect())
+        }
+    }
+
+    fn exec_returning_one<'c, C>(
+        &self,
+        connection: &mut C,
+    ) -> impl Future<Output = Result<Self::Row, Error<C>>>
+    where
+        C: Connection,
+        Self::Params: Params<C::Params<'c>>,
+        for<'b> Self::Row: From<&'b C::Row>,
+    {
+        async {
+            self.exec_returning_opt(connection)
+                .await
+                .and_then(|res| res.ok_or(Error::RowCount))
+        }
+    }
+
+    fn exec_returning_opt<'c, C>(
+        &self,
+        connection: &mut C,
+    ) -> impl Future<Output = Result<Option<Self::Row>, Error<C>>>
+    where
+        C: Connection,
+        Self::Params: Params<C::Params<'c>>,
+        for<'b> Self::Row: From<&'b C::Row>,
+    {
+        async {
+            self.exec_returning(connection).await.and_then(|res| {
+                let mut iter = res.into_iter();
+                let row = iter.next();
+                if row.is_some() && iter.next().is_some() {
+                    return Err(Error::RowCount);
+                }
+                Ok(row)
+            })
+        }
+    }
+
+    fn exec_returning_sync<'c, C>(&self, connection: &mut C) -> Result<Vec<Self::Row>, Error<C>>
+    where
+        C: Connection,
+        Self::Params: Params<C::Params<'c>>,
+        for<'b> Self::Row: From<&'b C::Row>,
+    {
+        self.exec_returning(connection).block_on()
+    }
+
+    fn exec_returning_one_sync<'c, C>(&self, connection: &mut C) -> Result<Self::Row, Error<C>>
+    where
+        C: Connection,
+        Self::Params: Params<C::Params<'c>>,
+        for<'b> Self::Row: From<&'b C::Row>,
+    {
+        self.exec_returning_one(connection).block_on()
+    }
+
+    fn exec_returning_opt_sync<'c, C>(
+        &self,
+        connection: &mut C,
+    ) -> Result<Option<Self::Row>, Error<C>>
+    where
+        C: Connection,
+        Self::Params: Params<C::Params<'c>>,
+        for<'b> Self::Row: From<&'b C::Row>,
+    {
+        self.exec_returning_opt(connection).block_on()
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt::Write;
+
+use super::*;
+
+pub struct OnConflict {
+    target: &'static [&'static Column],
+    action: OnConflictAction,
+}
+
+impl OnConflict {
+    pub const fn new(target: &'static [&'static Column], action: OnConflictAction) -> Self {
+        Self { target, action }
+    }
+
+    pub const fn target(&self) -> &'static [&'static Column] {
+        self.target
+    }
+
+    pub const fn action(&self) -> &OnConflictAction {
+        &self.action
+    }
+
+    pub fn fmt_sql<D: sql::Dialect>(&self, formatter: &mut sql::Formatter<D>) -> std::fmt::Result {
+        formatter.write_str(" on conflict")?;
+
+        if !self.target.is_empty() {
+            formatter.write_str(" (")?;
+            for (index, column) in self.target.iter().enumerate() {
+                formatter.write_ident(column.name())?;
+                if index != self.target.len() - 1 {
+                    formatter.write_str(", ")?;
+                }
+            }
+            formatter.write_str(")")?;
+        }
+
+        match &self.action {
+            OnConflictAction::DoNothing => formatter.write_str(" do nothing")?,
+            OnConflictAction::DoUpdate {
+                assignments,
+                filter,
+            } => {
+                formatter.write_str(" do update set ")?;
+                for (index, assignment) in assignments.iter().enumerate() {
+                    assignment.fmt_sql(formatter)?;
+                    if index != assignments.len() - 1 {
+                        formatter.write_str(", ")?;
+                    }
+                }
+                if let Some(filter) = filter {
+                    formatter.write_str(" where ")?;
+                    filter.fmt_sql(formatter)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub enum OnConflictAction {
+    DoNothing,
+    DoUpdate {
+        assignments: &'static [Assignment],
+        filter: Option<Expr>,
+    },
+}
@@ -0,0 +1,143 @@
+use std::fmt::Write;
+
+use super::*;
+
+pub trait InsertRecord {
+    const TABLE: &'static Table;
+
+    fn columns(&self) -> Vec<&'static Column>;
+
+    fn to_sql<D: sql::Dialect>(&self) -> String {
+        sql::to_sql_string::<D>(None, |formatter| self.fmt_sql(formatter))
+    }
+
+    fn fmt_sql<D: sql::Dialect>(&self, formatter: &mut sql::Formatter<D>) -> std::fmt::Result {
+        let columns = self.columns();
+
+        formatter.write_str("insert into ")?;
+        formatter.write_table_ident(Self::TABLE)?;
+        if columns.is_empty() {
+            return formatter.write_str(" default values");
+        }
+
+        formatter.write_str(" (")?;
+        for (index, column) in columns.iter().enumerate() {
+            formatter.write_ident(column.name())?;
+            if index != columns.len() - 1 {
+                formatter.write_str(", ")?;
+            }
+        }
+        formatter.write_str(") values (")?;
+        for (index, column) in columns.iter().enumerate() {
+            formatter.write_bind_param(column.name(), index as u32)?;
+            if index != columns.len() - 1 {
+                formatter.write_str(", ")?;
+            }
+        }
+        formatter.write_str(")")
+    }
+
+    fn exec<'c, C>(&'c self, connection: &mut C) -> impl Future<Output = Result<u64, Error<C>>>
+    where
+        C: Connection,
+        &'c Self: Params<C::Params<'c>>,
+    {
+        async move {
+            let sql = sql::to_sql_string::<C::Dialect>(connection.schema(), |formatter| {
+                self.fmt_sql(formatter)
+            });
+            match connection.execute(&sql, &self.to_driver()).await {
+                Ok(row_count) => Ok(row_count),
+                Err(error) => Err(Error::Connection(error)),
+            }
+        }
+    }
+
+    fn exec_sync<'c, C>(&'c self, connection: &mut C) -> Result<u64, Error<C>>
+    where
+        C: Connection,
+        &'c Self: Params<C::Params<'c>>,
+    {
+        self.exec(connection).block_on()
+    }
+}
+
+pub trait UpdateRecord {
+    type Key;
+    type Params<'a>
+    where
+        Self: 'a;
+
+    const TABLE: &'static Table;
+    const KEY_COLUMNS: &'static [&'static Column];
+
+    fn columns(&self) -> Vec<&'static Column>;
+
+    fn params<'a>(&'a self, key: &'a Self::Key) -> Self::Params<'a>;
+
+    fn to_sql<D: sql::Dialect>(&self) -> String {
+        sql::to_sql_string::<D>(None, |formatter| self.fmt_sql(formatter))
+    }
+
+    fn fmt_sql<D: sql::Dialect>(&self, formatter: &mut sql::Formatter<D>) -> std::fmt::Result {
+        let columns = self.columns();
+
+        formatter.write_str("update ")?;
+        formatter.write_table_ident(Self::TABLE)?;
+        formatter.write_str(" set ")?;
+        for (index, column) in columns.iter().enumerate() {
+            formatter.write_ident(column.name())?;
+            formatter.write_str(" = ")?;
+            formatter.write_bind_param(column.name(), index as u32)?;
+            if index != columns.len() - 1 {
+                formatter.write_str(", ")?;
+            }
+        }
+        formatter.write_str(" where ")?;
+        for (index, column) in Self::KEY_COLUMNS.iter().enumerate() {
+            formatter.write_ident(column.name())?;
+            formatter.write_str(" = ")?;
+            formatter.write_bind_param(column.name(), (columns.len() + index) as u32)?;
+            if index != Self::KEY_COLUMNS.len() - 1 {
+                formatter.write_str(" and ")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn exec<'c, C>(
+        &'c self,
+        connection: &mut C,
+        key: &'c Self::Key,
+    ) -> impl Future<Output = Result<u64, Error<C>>>
+    where
+        C: Connection,
+        Self::Params<'c>: Params<C::Params<'c>>,
+    {
+        async move {
+            // An empty patch would produce an invalid statement, and there is nothing to write.
+            if self.columns().is_empty() {
+                return Ok(0);
+            }
+
+            let sql = sql::to_sql_string::<C::Dialect>(connection.schema(), |formatter| {
+                self.fmt_sql(formatter)
+            });
+            match connection
+                .execute(&sql, &self.params(key).to_driver())
+                .await
+            {
+                Ok(row_count) => Ok(row_count),
+                Err(error) => Err(Error::Connection(error)),
+            }
+        }
+    }
+
+    fn exec_sync<'c, C>(&'c self, connection: &mut C, key: &'c Self::Key) -> Result<u64, Error<C>>
+    where
+        C: Connection,
+        Self::Params<'c>: Params<C::Params<'c>>,
+    {
+        self.exec(connection, key).block_on()
+    }
+}
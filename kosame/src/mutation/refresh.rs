@@ -0,0 +1,60 @@
+use std::fmt::Write;
+
+use super::*;
+
+/// A `refresh materialized view` statement.
+pub struct Refresh {
+    table: &'static Table,
+    concurrently: bool,
+}
+
+impl Refresh {
+    pub const fn new(table: &'static Table, concurrently: bool) -> Self {
+        Self {
+            table,
+            concurrently,
+        }
+    }
+
+    pub const fn table(&self) -> &'static Table {
+        self.table
+    }
+
+    pub const fn concurrently(&self) -> bool {
+        self.concurrently
+    }
+
+    pub fn to_sql<D: sql::Dialect>(&self) -> String {
+        sql::to_sql_string::<D>(None, |formatter| self.fmt_sql(formatter))
+    }
+
+    pub fn fmt_sql<D: sql::Dialect>(&self, formatter: &mut sql::Formatter<D>) -> std::fmt::Result {
+        formatter.write_str("refresh materialized view ")?;
+        if self.concurrently {
+            formatter.write_str("concurrently ")?;
+        }
+        formatter.write_table_ident(self.table)
+    }
+
+    pub async fn exec<'c, C>(&self, connection: &mut C) -> Result<(), Error<C>>
+    where
+        C: Connection,
+        (): Params<C::Params<'c>>,
+    {
+        let sql = sql::to_sql_string::<C::Dialect>(connection.schema(), |formatter| {
+            self.fmt_sql(formatter)
+        });
+        match connection.execute(&sql, &().to_driver()).await {
+            Ok(_) => Ok(()),
+            Err(error) => Err(Error::Connection(error)),
+        }
+    }
+
+    pub fn exec_sync<'c, C>(&self, connection: &mut C) -> Result<(), Error<C>>
+    where
+        C: Connection,
+        (): Params<C::Params<'c>>,
+    {
+        self.exec(connection).block_on()
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::Write;
+
+use super::*;
+
+pub struct Returning {
+    star: bool,
+    fields: &'static [ReturningField],
+}
+
+impl Returning {
+    pub const fn new(star: bool, fields: &'static [ReturningField]) -> Self {
+        Self { star, fields }
+    }
+
+    pub const fn star(&self) -> bool {
+        self.star
+    }
+
+    pub const fn fields(&self) -> &'static [ReturningField] {
+        self.fields
+    }
+
+    pub fn fmt_sql<D: sql::Dialect>(
+        &self,
+        formatter: &mut sql::Formatter<D>,
+        table: &Table,
+    ) -> std::fmt::Result {
+        formatter.write_str(" returning ")?;
+        self.fmt_fields_sql(formatter, table)
+    }
+
+    pub fn fmt_fields_sql<D: sql::Dialect>(
+        &self,
+        formatter: &mut sql::Formatter<D>,
+        table: &Table,
+    ) -> std::fmt::Result {
+        if self.star {
+            for (index, column) in table.columns().iter().enumerate() {
+                formatter.write_ident(column.name())?;
+                if index != table.columns().len() - 1 {
+                    formatter.write_str(", ")?;
+                }
+            }
+            if !self.fields.is_empty() {
+                formatter.write_str(", ")?;
+            }
+        }
+
+        for (index, field) in self.fields.iter().enumerate() {
+            match field {
+                ReturningField::Column { column, .. } => {
+                    formatter.write_ident(column.name())?;
+                }
+                ReturningField::Expr { expr, .. } => {
+                    expr.fmt_sql(formatter)?;
+                }
+            }
+            if index != self.fields.len() - 1 {
+                formatter.write_str(", ")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub enum ReturningField {
+    Column {
+        column: &'static Column,
+        alias: Option<&'static str>,
+    },
+    Expr {
+        expr: Expr,
+        alias: &'static str,
+    },
+}
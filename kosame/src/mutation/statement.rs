@@ -0,0 +1,17 @@
+use super::*;
+
+pub enum Statement {
+    Insert(Insert),
+    Update(Update),
+    Delete(Delete),
+}
+
+impl Statement {
+    pub fn fmt_sql<D: sql::Dialect>(&self, formatter: &mut sql::Formatter<D>) -> std::fmt::Result {
+        match self {
+            Self::Insert(inner) => inner.fmt_sql(formatter),
+            Self::Update(inner) => inner.fmt_sql(formatter),
+            Self::Delete(inner) => inner.fmt_sql(formatter),
+        }
+    }
+}
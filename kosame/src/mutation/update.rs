@@ -0,0 +1,66 @@
+use std::fmt::Write;
+
+use super::*;
+
+pub struct Update {
+    table: &'static Table,
+    assignments: &'static [Assignment],
+    filter: Option<Expr>,
+    returning: Option<Returning>,
+}
+
+impl Update {
+    pub const fn new(
+        table: &'static Table,
+        assignments: &'static [Assignment],
+        filter: Option<Expr>,
+        returning: Option<Returning>,
+    ) -> Self {
+        Self {
+            table,
+            assignments,
+            filter,
+            returning,
+        }
+    }
+
+    pub const fn table(&self) -> &'static Table {
+        self.table
+    }
+
+    pub const fn assignments(&self) -> &'static [Assignment] {
+        self.assignments
+    }
+
+    pub const fn filter(&self) -> Option<&Expr> {
+        self.filter.as_ref()
+    }
+
+    pub const fn returning(&self) -> Option<&Returning> {
+        self.returning.as_ref()
+    }
+
+    pub fn fmt_sql<D: sql::Dialect>(&self, formatter: &mut sql::Formatter<D>) -> std::fmt::Result {
+        formatter.write_str("update ")?;
+        formatter.write_table_ident(self.table)?;
+
+        formatter.write_str(" set ")?;
+        for (index, assignment) in self.assignments.iter().enumerate() {
+            assignment.fmt_sql(formatter)?;
+            if index != self.assignments.len() - 1 {
+                formatter.write_str(", ")?;
+            }
+        }
+
+        if let Some(filter) = &self.filter {
+            formatter.write_str(" where ")?;
+            filter.fmt_sql(formatter)?;
+        }
+
+        if let Some(returning) = &self.returning {
+            returning.fmt_sql(formatter, self.table)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Write;
 
-use crate::{Error, driver::Connection, sql};
+use crate::{Error, driver::Connection, schema::Junction, sql};
 
 use super::*;
 
@@ -8,11 +8,9 @@ pub struct RecordArrayRunner {}
 
 impl RecordArrayRunner {
     pub fn query_to_sql<D: sql::Dialect>(&self, query: &(impl Query + ?Sized)) -> String {
-        let mut sql = String::new();
-        let mut formatter = sql::Formatter::<D>::new(&mut sql);
-        fmt_node_sql(&mut formatter, query.root(), None)
-            .expect("string formatting should never fail");
-        sql
+        sql::to_sql_string::<D>(None, |formatter| {
+            fmt_node_sql(formatter, query.root(), None)
+        })
     }
 }
 
@@ -24,7 +22,9 @@ impl Runner for RecordArrayRunner {
         <Q as Query>::Params: Params<C::Params<'a>>,
         for<'b> <Q as Query>::Row: From<&'b C::Row>,
     {
-        let sql = self.query_to_sql::<C::Dialect>(query);
+        let sql = sql::to_sql_string::<C::Dialect>(connection.schema(), |formatter| {
+            fmt_node_sql(formatter, query.root(), None)
+        });
         let rows = match connection.query(&sql, &query.params().to_driver()).await {
             Ok(rows) => rows,
             Err(error) => return Err(Error::Connection(error)),
@@ -80,7 +80,7 @@ fn fmt_node_sql<D: sql::Dialect>(
     }
 
     formatter.write_str(" from ")?;
-    formatter.write_ident(node.table().name())?;
+    formatter.write_table_ident(node.table())?;
 
     if relation.is_some() || node.filter().is_some() {
         formatter.write_str(" where ")?;
@@ -91,16 +91,21 @@ fn fmt_node_sql<D: sql::Dialect>(
     }
 
     if let Some(relation) = relation {
-        for (index, (source_column, target_column)) in relation.column_pairs().enumerate() {
-            formatter.write_ident(relation.source_table())?;
-            formatter.write_str(".")?;
-            formatter.write_ident(source_column.name())?;
-            formatter.write_str(" = ")?;
-            formatter.write_ident(relation.target_table())?;
-            formatter.write_str(".")?;
-            formatter.write_ident(target_column.name())?;
-            if index != relation.source_columns().len() - 1 {
-                formatter.write_str(" and ")?;
+        match relation.junction() {
+            Some(junction) => fmt_junction_sql(formatter, relation, junction)?,
+            None => {
+                for (index, (source_column, target_column)) in relation.column_pairs().enumerate() {
+                    formatter.write_ident(relation.source_table())?;
+                    formatter.write_str(".")?;
+                    formatter.write_ident(source_column.name())?;
+                    formatter.write_str(" = ")?;
+                    formatter.write_ident(relation.target_table())?;
+                    formatter.write_str(".")?;
+                    formatter.write_ident(target_column.name())?;
+                    if index != relation.source_columns().len() - 1 {
+                        formatter.write_str(" and ")?;
+                    }
+                }
             }
         }
     }
@@ -133,3 +138,43 @@ fn fmt_node_sql<D: sql::Dialect>(
 
     Ok(())
 }
+
+fn fmt_junction_sql<D: sql::Dialect>(
+    formatter: &mut sql::Formatter<D>,
+    relation: &Relation,
+    junction: &Junction,
+) -> std::fmt::Result {
+    formatter.write_str("exists (select from ")?;
+    formatter.write_table_ident(junction.table())?;
+    formatter.write_str(" where ")?;
+
+    let pairs = relation
+        .source_columns()
+        .iter()
+        .zip(junction.source_columns())
+        .map(|(column, junction_column)| (relation.source_table(), column, junction_column))
+        .chain(
+            relation
+                .target_columns()
+                .iter()
+                .zip(junction.target_columns())
+                .map(|(column, junction_column)| {
+                    (relation.target_table(), column, junction_column)
+                }),
+        )
+        .collect::<Vec<_>>();
+    for (index, (table, column, junction_column)) in pairs.iter().enumerate() {
+        formatter.write_ident(junction.table().name())?;
+        formatter.write_str(".")?;
+        formatter.write_ident(junction_column.name())?;
+        formatter.write_str(" = ")?;
+        formatter.write_ident(table)?;
+        formatter.write_str(".")?;
+        formatter.write_ident(column.name())?;
+        if index != pairs.len() - 1 {
+            formatter.write_str(" and ")?;
+        }
+    }
+
+    formatter.write_str(")")
+}
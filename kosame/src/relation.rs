@@ -57,3 +57,15 @@ impl<T> OneToMany<T> {
         self.0
     }
 }
+
+/// Implemented by the relation types that nested inserts can follow. The child rows of a nested
+/// insert reference their parent row, so only one-to-many relations qualify.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "nested inserts only work through one-to-many relations",
+    label = "this relation is `{Self}`",
+    note = "declare the relation with `<=` on the parent table, e.g. `comments: (id) <= comments (post_id)`"
+)]
+pub trait NestedInsertRelation {}
+
+impl<T> NestedInsertRelation for OneToMany<T> {}
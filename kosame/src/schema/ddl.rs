@@ -0,0 +1,688 @@
+use std::fmt::Write;
+
+use super::*;
+use crate::sql::{self, Dialect, Formatter, IndexMethodPosition};
+
+// Data types, defaults and check expressions are written as declared. The dialect decides how
+// identity and generated columns are spelled, and how indexes and foreign key cycles are created.
+
+impl Schema {
+    /// Renders the `create table` statements of all tables. Tables are ordered so that the
+    /// targets of foreign keys are created first. If tables reference each other in a cycle, the
+    /// foreign keys that cannot be created yet are added by trailing `alter table` statements,
+    /// unless the dialect accepts foreign keys to tables that do not exist yet.
+    pub fn to_create_sql<D: Dialect>(&self) -> String {
+        sql::to_sql_string::<D>(None, |formatter| self.fmt_create_sql(formatter))
+    }
+
+    pub fn fmt_create_sql<D: Dialect>(&self, formatter: &mut Formatter<D>) -> std::fmt::Result {
+        let mut remaining = self.tables.to_vec();
+        let mut deferred = vec![];
+        while !remaining.is_empty() {
+            let depends_on_remaining = |table: &Table, constraint: &Constraint| {
+                remaining
+                    .iter()
+                    .any(|other| !std::ptr::eq(table, *other) && constraint.references(other))
+            };
+            let next = remaining
+                .iter()
+                .position(|table| {
+                    !table
+                        .constraints
+                        .iter()
+                        .any(|constraint| depends_on_remaining(table, constraint))
+                })
+                .unwrap_or(0);
+            let table = remaining[next];
+            let table_deferred = table
+                .constraints
+                .iter()
+                .filter(|constraint| {
+                    D::alter_table_add_constraint() && depends_on_remaining(table, constraint)
+                })
+                .collect::<Vec<_>>();
+            table.fmt_create_table_sql(formatter, &table_deferred)?;
+            deferred.extend(
+                table_deferred
+                    .into_iter()
+                    .map(|constraint| (table, constraint)),
+            );
+            remaining.remove(next);
+        }
+
+        for (table, constraint) in deferred {
+            formatter.write_str("alter table ")?;
+            formatter.write_table_ident(table)?;
+            formatter.write_str(" add ")?;
+            constraint.fmt_sql(formatter)?;
+            formatter.write_str(";\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl Table {
+    /// Renders the `create table` statement of this table, followed by a `create index` statement
+    /// for each of its indexes. Views are skipped, because Kosame does not know the query behind
+    /// them.
+    pub fn to_create_sql<D: Dialect>(&self) -> String {
+        sql::to_sql_string::<D>(None, |formatter| self.fmt_create_sql(formatter))
+    }
+
+    pub fn fmt_create_sql<D: Dialect>(&self, formatter: &mut Formatter<D>) -> std::fmt::Result {
+        self.fmt_create_table_sql(formatter, &[])
+    }
+
+    /// Like [`Table::fmt_create_sql`], but leaves out the `deferred` constraints.
+    fn fmt_create_table_sql<D: Dialect>(
+        &self,
+        formatter: &mut Formatter<D>,
+        deferred: &[&Constraint],
+    ) -> std::fmt::Result {
+        if self.kind != TableKind::Table {
+            return Ok(());
+        }
+
+        let constraints = self
+            .constraints
+            .iter()
+            .filter(|constraint| {
+                !deferred
+                    .iter()
+                    .any(|other| std::ptr::eq(*constraint, *other))
+            })
+            .collect::<Vec<_>>();
+
+        formatter.write_str("create table ")?;
+        formatter.write_table_ident(self)?;
+        formatter.write_str(" (\n")?;
+        for (index, column) in self.columns.iter().enumerate() {
+            formatter.write_str("    ")?;
+            column.fmt_definition_sql(formatter)?;
+            if index != self.columns.len() - 1 || !constraints.is_empty() {
+                formatter.write_str(",")?;
+            }
+            formatter.write_str("\n")?;
+        }
+        for (index, constraint) in constraints.iter().enumerate() {
+            formatter.write_str("    ")?;
+            constraint.fmt_sql(formatter)?;
+            if index != constraints.len() - 1 {
+                formatter.write_str(",")?;
+            }
+            formatter.write_str("\n")?;
+        }
+        formatter.write_str(");\n")?;
+
+        for index in self.indexes {
+            index.fmt_create_sql(formatter, self)?;
+            formatter.write_str(";\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Column {
+    /// Renders the column as part of a `create table` statement, e.g. `"id" int not null`.
+    pub fn fmt_definition_sql<D: Dialect>(&self, formatter: &mut Formatter<D>) -> std::fmt::Result {
+        formatter.write_ident(self.name)?;
+        D::fmt_column_type(formatter, self)?;
+        if let Some(default) = &self.default {
+            formatter.write_str(" default ")?;
+            default.fmt_sql(formatter)?;
+        }
+        if !self.nullable {
+            formatter.write_str(" not null")?;
+        }
+        Ok(())
+    }
+}
+
+impl DataType {
+    /// Renders the type as declared, as part of a `create table` statement.
+    pub fn fmt_sql<D: Dialect>(&self, formatter: &mut Formatter<D>) -> std::fmt::Result {
+        formatter.write_str(self.name)?;
+        self.fmt_modifiers(formatter)
+    }
+}
+
+impl Generated {
+    /// Renders the standard `generated always as (expr) stored` clause of a generated column,
+    /// starting with a space. Identity columns are left to the dialect.
+    pub fn fmt_stored_sql<D: Dialect>(&self, formatter: &mut Formatter<D>) -> std::fmt::Result {
+        if let Generated::Stored { expr } = self {
+            formatter.write_str(" generated always as (")?;
+            expr.fmt_sql(formatter)?;
+            formatter.write_str(") stored")?;
+        }
+        Ok(())
+    }
+}
+
+impl Constraint {
+    /// Whether this is a foreign key referencing the given table.
+    fn references(&self, table: &Table) -> bool {
+        match &self.kind {
+            ConstraintKind::ForeignKey {
+                target_schema,
+                target_table,
+                ..
+            } => *target_table == table.name && *target_schema == table.schema,
+            _ => false,
+        }
+    }
+
+    /// Renders the constraint as part of a `create table` or `alter table` statement.
+    pub fn fmt_sql<D: Dialect>(&self, formatter: &mut Formatter<D>) -> std::fmt::Result {
+        if let Some(name) = self.name {
+            formatter.write_str("constraint ")?;
+            formatter.write_ident(name)?;
+            formatter.write_str(" ")?;
+        }
+        match &self.kind {
+            ConstraintKind::PrimaryKey { columns } => {
+                formatter.write_str("primary key ")?;
+                fmt_column_list_sql(formatter, columns)
+            }
+            ConstraintKind::Unique { columns } => {
+                formatter.write_str("unique ")?;
+                fmt_column_list_sql(formatter, columns)
+            }
+            ConstraintKind::Check { expr } => {
+                formatter.write_str("check (")?;
+                expr.fmt_sql(formatter)?;
+                formatter.write_str(")")
+            }
+            ConstraintKind::ForeignKey {
+                columns,
+                target_schema,
+                target_table,
+                target_columns,
+                on_delete,
+                on_update,
+            } => {
+                formatter.write_str("foreign key ")?;
+                fmt_column_list_sql(formatter, columns)?;
+                formatter.write_str(" references ")?;
+                formatter.write_table_name(*target_schema, target_table)?;
+                formatter.write_str(" ")?;
+                fmt_column_list_sql(formatter, target_columns)?;
+                if *on_delete != ReferentialAction::NoAction {
+                    write!(formatter, " on delete {}", on_delete.as_sql())?;
+                }
+                if *on_update != ReferentialAction::NoAction {
+                    write!(formatter, " on update {}", on_update.as_sql())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Index {
+    /// Renders the `create index` statement of this index on the given table. If the dialect
+    /// requires a name, unnamed indexes are named like PostgreSQL would, e.g. `posts_title_idx`.
+    pub fn fmt_create_sql<D: Dialect>(
+        &self,
+        formatter: &mut Formatter<D>,
+        table: &Table,
+    ) -> std::fmt::Result {
+        formatter.write_str("create ")?;
+        if self.unique {
+            formatter.write_str("unique ")?;
+        }
+        formatter.write_str("index ")?;
+        match self.name {
+            Some(name) => {
+                formatter.write_ident(name)?;
+                formatter.write_str(" ")?;
+            }
+            None if !D::anonymous_indexes() => {
+                let mut name = table.name.to_string();
+                for column in self.columns {
+                    name.push('_');
+                    name.push_str(column.name);
+                }
+                name.push_str("_idx");
+                formatter.write_ident(&name)?;
+                formatter.write_str(" ")?;
+            }
+            None => {}
+        }
+        formatter.write_str("on ")?;
+        formatter.write_table_ident(table)?;
+        if let Some(method) = self.method
+            && let Some(IndexMethodPosition::BeforeColumns) = D::index_method_position()
+        {
+            write!(formatter, " using {method}")?;
+        }
+        formatter.write_str(" ")?;
+        fmt_column_list_sql(formatter, self.columns)?;
+        if let Some(method) = self.method
+            && let Some(IndexMethodPosition::AfterColumns) = D::index_method_position()
+        {
+            write!(formatter, " using {method}")?;
+        }
+        if let Some(predicate) = &self.predicate {
+            formatter.write_str(" where ")?;
+            predicate.fmt_sql(formatter)?;
+        }
+        Ok(())
+    }
+}
+
+fn fmt_column_list_sql<D: Dialect>(
+    formatter: &mut Formatter<D>,
+    columns: &[&Column],
+) -> std::fmt::Result {
+    formatter.write_str("(")?;
+    for (index, column) in columns.iter().enumerate() {
+        formatter.write_ident(column.name())?;
+        if index != columns.len() - 1 {
+            formatter.write_str(", ")?;
+        }
+    }
+    formatter.write_str(")")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::{BinOp, Binary, ColumnRef, Lit};
+    #[cfg(feature = "dbms-postgres")]
+    use crate::postgres;
+
+    const AUTHOR_ID: Column = Column::new(
+        "id",
+        None,
+        DataType::new("int", &[], None, &[]),
+        false,
+        true,
+        None,
+        Some(Generated::Identity { always: true }),
+    );
+
+    const AUTHORS: Table = Table::new(
+        None,
+        "authors",
+        TableKind::Table,
+        &[&AUTHOR_ID],
+        &[],
+        &[Constraint::new(
+            None,
+            ConstraintKind::PrimaryKey {
+                columns: &[&AUTHOR_ID],
+            },
+        )],
+        &[],
+    );
+
+    const ID: Column = Column::new(
+        "id",
+        None,
+        DataType::new("bigint", &[], None, &[]),
+        false,
+        true,
+        None,
+        Some(Generated::Identity { always: false }),
+    );
+    const AUTHOR: Column = Column::new(
+        "author",
+        None,
+        DataType::new("int", &[], None, &[]),
+        true,
+        false,
+        None,
+        None,
+    );
+    const PRICE: Column = Column::new(
+        "price",
+        None,
+        DataType::new("numeric", &[10, 2], None, &[]),
+        false,
+        false,
+        Some(Expr::Lit(Lit::Int(0))),
+        None,
+    );
+    const PRICE_REF: Expr = Expr::ColumnRef(ColumnRef::new(None, &PRICE));
+    const DOUBLE_PRICE: Column = Column::new(
+        "double_price",
+        None,
+        DataType::new("numeric", &[], None, &[]),
+        true,
+        false,
+        None,
+        Some(Generated::Stored {
+            expr: Expr::Binary(Binary::new(
+                &PRICE_REF,
+                BinOp::Multiply,
+                &Expr::Lit(Lit::Int(2)),
+            )),
+        }),
+    );
+    const FLAG: Column = Column::new(
+        "flag",
+        None,
+        DataType::new("char", &[], None, &[]),
+        true,
+        false,
+        None,
+        None,
+    );
+    const CODE: Column = Column::new(
+        "code",
+        None,
+        DataType::new("char", &[3], None, &[]),
+        true,
+        false,
+        None,
+        None,
+    );
+    const SEEN_AT: Column = Column::new(
+        "seen_at",
+        None,
+        DataType::new("timestamp", &[3], Some(true), &[None]),
+        false,
+        false,
+        None,
+        None,
+    );
+
+    const BOOKS: Table = Table::new(
+        Some("library"),
+        "books",
+        TableKind::Table,
+        &[&ID, &AUTHOR, &PRICE, &DOUBLE_PRICE, &FLAG, &CODE, &SEEN_AT],
+        &[],
+        &[
+            Constraint::new(None, ConstraintKind::PrimaryKey { columns: &[&ID] }),
+            Constraint::new(
+                Some("books_code_key"),
+                ConstraintKind::Unique { columns: &[&CODE] },
+            ),
+            Constraint::new(
+                None,
+                ConstraintKind::Check {
+                    expr: Expr::Binary(Binary::new(
+                        &PRICE_REF,
+                        BinOp::GreaterThanOrEq,
+                        &Expr::Lit(Lit::Int(0)),
+                    )),
+                },
+            ),
+            Constraint::new(
+                Some("books_author_fkey"),
+                ConstraintKind::ForeignKey {
+                    columns: &[&AUTHOR],
+                    target_schema: None,
+                    target_table: "authors",
+                    target_columns: &[&AUTHOR_ID],
+                    on_delete: ReferentialAction::SetNull,
+                    on_update: ReferentialAction::Cascade,
+                },
+            ),
+        ],
+        &[
+            Index::new(None, false, &[&AUTHOR], None, None),
+            Index::new(
+                Some("books_flag_idx"),
+                true,
+                &[&FLAG, &CODE],
+                Some("btree"),
+                Some(Expr::Binary(Binary::new(
+                    &PRICE_REF,
+                    BinOp::GreaterThan,
+                    &Expr::Lit(Lit::Int(0)),
+                ))),
+            ),
+        ],
+    );
+
+    const VIEW: Table = Table::new(None, "book_view", TableKind::View, &[&ID], &[], &[], &[]);
+
+    const EMPLOYEE_ID: Column = Column::new(
+        "id",
+        None,
+        DataType::new("int", &[], None, &[]),
+        false,
+        true,
+        None,
+        None,
+    );
+    const EMPLOYEE_MANAGER: Column = Column::new(
+        "manager",
+        None,
+        DataType::new("int", &[], None, &[]),
+        true,
+        false,
+        None,
+        None,
+    );
+    const EMPLOYEE_TEAM: Column = Column::new(
+        "team",
+        None,
+        DataType::new("int", &[], None, &[]),
+        true,
+        false,
+        None,
+        None,
+    );
+    const TEAM_ID: Column = Column::new(
+        "id",
+        None,
+        DataType::new("int", &[], None, &[]),
+        false,
+        true,
+        None,
+        None,
+    );
+    const TEAM_LEAD: Column = Column::new(
+        "lead",
+        None,
+        DataType::new("int", &[], None, &[]),
+        true,
+        false,
+        None,
+        None,
+    );
+
+    const EMPLOYEES: Table = Table::new(
+        None,
+        "employees",
+        TableKind::Table,
+        &[&EMPLOYEE_ID, &EMPLOYEE_MANAGER, &EMPLOYEE_TEAM],
+        &[],
+        &[
+            Constraint::new(
+                None,
+                ConstraintKind::ForeignKey {
+                    columns: &[&EMPLOYEE_MANAGER],
+                    target_schema: None,
+                    target_table: "employees",
+                    target_columns: &[&EMPLOYEE_ID],
+                    on_delete: ReferentialAction::NoAction,
+                    on_update: ReferentialAction::NoAction,
+                },
+            ),
+            Constraint::new(
+                Some("employees_team_fkey"),
+                ConstraintKind::ForeignKey {
+                    columns: &[&EMPLOYEE_TEAM],
+                    target_schema: None,
+                    target_table: "teams",
+                    target_columns: &[&TEAM_ID],
+                    on_delete: ReferentialAction::Cascade,
+                    on_update: ReferentialAction::NoAction,
+                },
+            ),
+        ],
+        &[],
+    );
+
+    const TEAMS: Table = Table::new(
+        None,
+        "teams",
+        TableKind::Table,
+        &[&TEAM_ID, &TEAM_LEAD],
+        &[],
+        &[Constraint::new(
+            None,
+            ConstraintKind::ForeignKey {
+                columns: &[&TEAM_LEAD],
+                target_schema: None,
+                target_table: "employees",
+                target_columns: &[&EMPLOYEE_ID],
+                on_delete: ReferentialAction::NoAction,
+                on_update: ReferentialAction::NoAction,
+            },
+        )],
+        &[],
+    );
+
+    #[cfg(feature = "dbms-postgres")]
+    #[test]
+    fn create_table() {
+        assert_eq!(
+            BOOKS.to_create_sql::<postgres::Dialect>(),
+            r#"create table "library"."books" (
+    "id" bigint generated by default as identity not null,
+    "author" int,
+    "price" numeric(10, 2) default 0 not null,
+    "double_price" numeric generated always as ("price" * 2) stored,
+    "flag" "char",
+    "code" char(3),
+    "seen_at" timestamp(3) with time zone[] not null,
+    primary key ("id"),
+    constraint "books_code_key" unique ("code"),
+    check ("price" >= 0),
+    constraint "books_author_fkey" foreign key ("author") references "authors" ("id") on delete set null on update cascade
+);
+create index on "library"."books" ("author");
+create unique index "books_flag_idx" on "library"."books" using btree ("flag", "code") where "price" > 0;
+"#
+        );
+    }
+
+    #[cfg(feature = "dbms-postgres")]
+    #[test]
+    fn create_table_skips_views() {
+        assert_eq!(VIEW.to_create_sql::<postgres::Dialect>(), "");
+    }
+
+    #[cfg(feature = "dbms-postgres")]
+    #[test]
+    fn create_schema_orders_foreign_key_targets_first() {
+        assert_eq!(
+            Schema::new(&[&BOOKS, &AUTHORS, &VIEW]).to_create_sql::<postgres::Dialect>(),
+            format!(
+                "{}{}",
+                AUTHORS.to_create_sql::<postgres::Dialect>(),
+                BOOKS.to_create_sql::<postgres::Dialect>()
+            )
+        );
+    }
+
+    #[cfg(feature = "dbms-postgres")]
+    #[test]
+    fn create_schema_defers_foreign_key_cycles() {
+        assert_eq!(
+            Schema::new(&[&EMPLOYEES, &TEAMS]).to_create_sql::<postgres::Dialect>(),
+            r#"create table "employees" (
+    "id" int not null,
+    "manager" int,
+    "team" int,
+    foreign key ("manager") references "employees" ("id")
+);
+create table "teams" (
+    "id" int not null,
+    "lead" int,
+    foreign key ("lead") references "employees" ("id")
+);
+alter table "employees" add constraint "employees_team_fkey" foreign key ("team") references "teams" ("id") on delete cascade;
+"#
+        );
+    }
+
+    #[cfg(feature = "dbms-mysql")]
+    #[test]
+    fn create_table_mysql() {
+        assert_eq!(
+            BOOKS.to_create_sql::<crate::mysql::Dialect>(),
+            r#"create table `library`.`books` (
+    `id` bigint auto_increment not null,
+    `author` int,
+    `price` numeric(10, 2) default 0 not null,
+    `double_price` numeric generated always as (`price` * 2) stored,
+    `flag` char,
+    `code` char(3),
+    `seen_at` timestamp(3) with time zone[] not null,
+    primary key (`id`),
+    constraint `books_code_key` unique (`code`),
+    check (`price` >= 0),
+    constraint `books_author_fkey` foreign key (`author`) references `authors` (`id`) on delete set null on update cascade
+);
+create index `books_author_idx` on `library`.`books` (`author`);
+create unique index `books_flag_idx` on `library`.`books` (`flag`, `code`) using btree where `price` > 0;
+"#
+        );
+    }
+
+    #[cfg(feature = "dbms-mssql")]
+    #[test]
+    fn create_table_mssql() {
+        assert_eq!(
+            BOOKS.to_create_sql::<crate::mssql::Dialect>(),
+            r#"create table [library].[books] (
+    [id] bigint identity(1, 1) not null,
+    [author] int,
+    [price] numeric(10, 2) default 0 not null,
+    [double_price] as ([price] * 2) persisted,
+    [flag] char,
+    [code] char(3),
+    [seen_at] timestamp(3) with time zone[] not null,
+    primary key ([id]),
+    constraint [books_code_key] unique ([code]),
+    check ([price] >= 0),
+    constraint [books_author_fkey] foreign key ([author]) references [authors] ([id]) on delete set null on update cascade
+);
+create index [books_author_idx] on [library].[books] ([author]);
+create unique index [books_flag_idx] on [library].[books] ([flag], [code]) where [price] > 0;
+"#
+        );
+    }
+
+    #[cfg(feature = "dbms-sqlite")]
+    #[test]
+    fn create_table_sqlite() {
+        assert_eq!(
+            AUTHORS.to_create_sql::<crate::sqlite::Dialect>(),
+            r#"create table "authors" (
+    "id" int not null,
+    primary key ("id")
+);
+"#
+        );
+    }
+
+    #[cfg(feature = "dbms-sqlite")]
+    #[test]
+    fn create_schema_sqlite_keeps_foreign_key_cycles() {
+        assert_eq!(
+            Schema::new(&[&EMPLOYEES, &TEAMS]).to_create_sql::<crate::sqlite::Dialect>(),
+            r#"create table "employees" (
+    "id" int not null,
+    "manager" int,
+    "team" int,
+    foreign key ("manager") references "employees" ("id"),
+    constraint "employees_team_fkey" foreign key ("team") references "teams" ("id") on delete cascade
+);
+create table "teams" (
+    "id" int not null,
+    "lead" int,
+    foreign key ("lead") references "employees" ("id")
+);
+"#
+        );
+    }
+}
@@ -0,0 +1,430 @@
+use pollster::FutureExt;
+
+use super::*;
+use crate::{Error, driver::Connection, params::Params};
+
+/// A difference between a declared table and the database, as found by [`Schema::check_drift`].
+pub enum Drift {
+    MissingTable {
+        table: &'static Table,
+    },
+    MissingColumn {
+        table: &'static Table,
+        column: &'static Column,
+    },
+    TypeMismatch {
+        table: &'static Table,
+        column: &'static Column,
+        expected: String,
+        actual: String,
+    },
+    NullabilityMismatch {
+        table: &'static Table,
+        column: &'static Column,
+    },
+    MissingForeignKey {
+        table: &'static Table,
+        columns: &'static [&'static Column],
+        target_schema: Option<&'static str>,
+        target_table: &'static str,
+        target_columns: &'static [&'static Column],
+    },
+}
+
+impl std::fmt::Display for Drift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingTable { table } => {
+                write!(f, "table `{}` does not exist", QualifiedName(table))
+            }
+            Self::MissingColumn { table, column } => write!(
+                f,
+                "column `{}` of table `{}` does not exist",
+                column.name(),
+                QualifiedName(table)
+            ),
+            Self::TypeMismatch {
+                table,
+                column,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "column `{}` of table `{}` has type `{}`, expected `{}`",
+                column.name(),
+                QualifiedName(table),
+                actual,
+                expected
+            ),
+            Self::NullabilityMismatch { table, column } => write!(
+                f,
+                "column `{}` of table `{}` is {}, expected it to be {}",
+                column.name(),
+                QualifiedName(table),
+                if column.nullable() {
+                    "not null"
+                } else {
+                    "nullable"
+                },
+                if column.nullable() {
+                    "nullable"
+                } else {
+                    "not null"
+                },
+            ),
+            Self::MissingForeignKey {
+                table,
+                columns,
+                target_schema,
+                target_table,
+                target_columns,
+            } => {
+                write!(
+                    f,
+                    "foreign key ({}) of table `{}` referencing `",
+                    ColumnNames(columns),
+                    QualifiedName(table)
+                )?;
+                if let Some(target_schema) = target_schema {
+                    write!(f, "{target_schema}.")?;
+                }
+                write!(
+                    f,
+                    "{target_table}` ({}) does not exist",
+                    ColumnNames(target_columns)
+                )
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Drift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+struct QualifiedName<'a>(&'a Table);
+
+impl std::fmt::Display for QualifiedName<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(schema) = self.0.schema() {
+            write!(f, "{schema}.")?;
+        }
+        f.write_str(self.0.name())
+    }
+}
+
+struct ColumnNames<'a>(&'a [&'a Column]);
+
+impl std::fmt::Display for ColumnNames<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, column) in self.0.iter().enumerate() {
+            f.write_str(column.name())?;
+            if index != self.0.len() - 1 {
+                f.write_str(", ")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A column of a table or view, as read from `pg_catalog`.
+#[doc(hidden)]
+pub struct CatalogColumn {
+    pub schema: String,
+    pub table: String,
+    pub current_schema: bool,
+    pub column: Option<String>,
+    pub nullable: bool,
+    pub data_type: Option<String>,
+}
+
+/// A foreign key, as read from `pg_catalog`.
+#[doc(hidden)]
+pub struct CatalogForeignKey {
+    pub schema: String,
+    pub table: String,
+    pub columns: Vec<String>,
+    pub target_schema: String,
+    pub target_table: String,
+    pub target_columns: Vec<String>,
+}
+
+const COLUMNS_SQL: &str = "select n.nspname::text, c.relname::text, n.nspname = current_schema(), \
+    a.attname::text, not coalesce(a.attnotnull, false), format_type(a.atttypid, a.atttypmod) \
+    from pg_catalog.pg_class c \
+    join pg_catalog.pg_namespace n on n.oid = c.relnamespace \
+    left join pg_catalog.pg_attribute a on a.attrelid = c.oid and a.attnum > 0 and not a.attisdropped \
+    where c.relkind in ('r', 'p', 'v', 'm') \
+    and n.nspname not in ('pg_catalog', 'information_schema')";
+
+const FOREIGN_KEYS_SQL: &str = "select n.nspname::text, c.relname::text, \
+    array(select a.attname::text from unnest(con.conkey) with ordinality k(attnum, ord) \
+        join pg_catalog.pg_attribute a on a.attrelid = con.conrelid and a.attnum = k.attnum order by k.ord), \
+    tn.nspname::text, tc.relname::text, \
+    array(select a.attname::text from unnest(con.confkey) with ordinality k(attnum, ord) \
+        join pg_catalog.pg_attribute a on a.attrelid = con.confrelid and a.attnum = k.attnum order by k.ord) \
+    from pg_catalog.pg_constraint con \
+    join pg_catalog.pg_class c on c.oid = con.conrelid \
+    join pg_catalog.pg_namespace n on n.oid = c.relnamespace \
+    join pg_catalog.pg_class tc on tc.oid = con.confrelid \
+    join pg_catalog.pg_namespace tn on tn.oid = tc.relnamespace \
+    where con.contype = 'f'";
+
+impl Schema {
+    /// Compares the declared tables with the catalog of a PostgreSQL database and reports missing
+    /// tables, missing columns, type and nullability mismatches and missing foreign keys. Tables
+    /// declared without a schema are looked up in the schema of the connection, or the current
+    /// schema of the database. Anything that exists in the database but is not declared is
+    /// ignored.
+    pub async fn check_drift<'c, C>(&self, connection: &mut C) -> Result<Vec<Drift>, Error<C>>
+    where
+        C: Connection,
+        (): Params<C::Params<'c>>,
+        for<'b> CatalogColumn: TryFrom<&'b C::Row, Error = C::Error>,
+        for<'b> CatalogForeignKey: TryFrom<&'b C::Row, Error = C::Error>,
+    {
+        let columns = connection
+            .query(COLUMNS_SQL, &().to_driver())
+            .await
+            .and_then(|rows| {
+                rows.iter()
+                    .map(CatalogColumn::try_from)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(Error::Connection)?;
+        let foreign_keys = connection
+            .query(FOREIGN_KEYS_SQL, &().to_driver())
+            .await
+            .and_then(|rows| {
+                rows.iter()
+                    .map(CatalogForeignKey::try_from)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(Error::Connection)?;
+
+        let default_schema = connection.schema();
+        let in_schema = |schema: Option<&str>, current_schema: bool, actual: &str| match schema
+            .or(default_schema)
+        {
+            Some(schema) => schema == actual,
+            None => current_schema,
+        };
+
+        let mut drift = vec![];
+        for table in self.tables {
+            let table_columns = columns
+                .iter()
+                .filter(|column| {
+                    column.table == table.name()
+                        && in_schema(table.schema(), column.current_schema, &column.schema)
+                })
+                .collect::<Vec<_>>();
+            if table_columns.is_empty() {
+                drift.push(Drift::MissingTable { table });
+                continue;
+            }
+
+            for column in table.columns() {
+                let Some(actual) = table_columns
+                    .iter()
+                    .find(|actual| actual.column.as_deref() == Some(column.name()))
+                else {
+                    drift.push(Drift::MissingColumn { table, column });
+                    continue;
+                };
+
+                let expected = normalize_data_type(column.data_type());
+                let actual_type = actual.data_type.as_deref().unwrap_or_default();
+                // User-defined types outside the search path are qualified by their schema, which
+                // is expected to be the schema of the table.
+                if expected != actual_type && actual_type != format!("{}.{expected}", actual.schema)
+                {
+                    drift.push(Drift::TypeMismatch {
+                        table,
+                        column,
+                        expected,
+                        actual: actual_type.to_string(),
+                    });
+                }
+
+                // The database does not track the nullability of view columns.
+                if table.kind() == TableKind::Table && column.nullable() != actual.nullable {
+                    drift.push(Drift::NullabilityMismatch { table, column });
+                }
+            }
+
+            let schema = &table_columns[0].schema;
+            for constraint in table.constraints() {
+                let ConstraintKind::ForeignKey {
+                    columns,
+                    target_schema,
+                    target_table,
+                    target_columns,
+                    ..
+                } = constraint.kind()
+                else {
+                    continue;
+                };
+                let exists = foreign_keys.iter().any(|actual| {
+                    &actual.schema == schema
+                        && actual.table == table.name()
+                        && actual.target_table == *target_table
+                        && target_schema
+                            .is_none_or(|target_schema| actual.target_schema == target_schema)
+                        && names_match(columns, &actual.columns)
+                        && names_match(target_columns, &actual.target_columns)
+                });
+                if !exists {
+                    drift.push(Drift::MissingForeignKey {
+                        table,
+                        columns,
+                        target_schema: *target_schema,
+                        target_table,
+                        target_columns,
+                    });
+                }
+            }
+        }
+
+        Ok(drift)
+    }
+
+    pub fn check_drift_sync<'c, C>(&self, connection: &mut C) -> Result<Vec<Drift>, Error<C>>
+    where
+        C: Connection,
+        (): Params<C::Params<'c>>,
+        for<'b> CatalogColumn: TryFrom<&'b C::Row, Error = C::Error>,
+        for<'b> CatalogForeignKey: TryFrom<&'b C::Row, Error = C::Error>,
+    {
+        self.check_drift(connection).block_on()
+    }
+}
+
+fn names_match(columns: &[&Column], names: &[String]) -> bool {
+    columns.len() == names.len()
+        && columns
+            .iter()
+            .zip(names)
+            .all(|(column, name)| column.name() == name)
+}
+
+/// Spells a declared data type the way PostgreSQL's `format_type` prints it, e.g. `serial` as
+/// `integer` and `numeric(10, 2)` as `numeric(10,2)`.
+fn normalize_data_type(data_type: &DataType) -> String {
+    let params = data_type.params();
+    let (name, params) = match data_type.name() {
+        "int" | "integer" | "int4" | "serial" | "serial4" => ("integer", params),
+        "smallint" | "int2" | "smallserial" | "serial2" => ("smallint", params),
+        "bigint" | "int8" | "bigserial" | "serial8" => ("bigint", params),
+        "real" | "float4" => ("real", params),
+        "double precision" | "float8" => ("double precision", params),
+        "float" => match params.first() {
+            Some(precision) if *precision <= 24 => ("real", &[][..]),
+            _ => ("double precision", &[][..]),
+        },
+        "bool" | "boolean" => ("boolean", params),
+        "decimal" => ("numeric", params),
+        "varchar" | "character varying" => ("character varying", params),
+        "char varying" => ("character varying", params),
+        // A bare `char` column is read as `i8`, i.e. the single-byte `"char"` type.
+        "char" if params.is_empty() => ("\"char\"", params),
+        "char" | "character" | "bpchar" if params.is_empty() => ("character", &[1][..]),
+        "char" | "character" | "bpchar" => ("character", params),
+        "bit" if params.is_empty() => ("bit", &[1][..]),
+        "varbit" | "bit varying" => ("bit varying", params),
+        "timestamptz" => ("timestamp", params),
+        "timetz" => ("time", params),
+        name => (name, params),
+    };
+
+    let mut result = name.to_string();
+    if !params.is_empty() {
+        result.push('(');
+        result.push_str(
+            &params
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        result.push(')');
+    }
+    if name == "timestamp" || name == "time" {
+        let time_zone = matches!(data_type.name(), "timestamptz" | "timetz")
+            || data_type.time_zone() == Some(true);
+        result.push_str(if time_zone {
+            " with time zone"
+        } else {
+            " without time zone"
+        });
+    }
+    if !data_type.array_dimensions().is_empty() {
+        result.push_str("[]");
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The expected strings are the outputs of `format_type` for columns declared with these types.
+    #[test]
+    fn normalize_data_types() {
+        let cases = [
+            (DataType::new("serial", &[], None, &[]), "integer"),
+            (DataType::new("int4", &[], None, &[]), "integer"),
+            (DataType::new("smallserial", &[], None, &[]), "smallint"),
+            (DataType::new("int8", &[], None, &[]), "bigint"),
+            (DataType::new("float", &[10], None, &[]), "real"),
+            (DataType::new("float", &[40], None, &[]), "double precision"),
+            (DataType::new("float", &[], None, &[]), "double precision"),
+            (DataType::new("bool", &[], None, &[]), "boolean"),
+            (
+                DataType::new("decimal", &[10, 2], None, &[]),
+                "numeric(10,2)",
+            ),
+            (DataType::new("numeric", &[], None, &[]), "numeric"),
+            (
+                DataType::new("varchar", &[64], None, &[]),
+                "character varying(64)",
+            ),
+            (DataType::new("char", &[], None, &[]), "\"char\""),
+            (DataType::new("char", &[3], None, &[]), "character(3)"),
+            (DataType::new("character", &[], None, &[]), "character(1)"),
+            (DataType::new("bit", &[], None, &[]), "bit(1)"),
+            (DataType::new("varbit", &[5], None, &[]), "bit varying(5)"),
+            (
+                DataType::new("timestamptz", &[], None, &[]),
+                "timestamp with time zone",
+            ),
+            (
+                DataType::new("timestamp", &[3], Some(true), &[]),
+                "timestamp(3) with time zone",
+            ),
+            (
+                DataType::new("timestamp", &[], None, &[]),
+                "timestamp without time zone",
+            ),
+            (
+                DataType::new("timetz", &[], None, &[]),
+                "time with time zone",
+            ),
+            (
+                DataType::new("time", &[2], Some(false), &[]),
+                "time(2) without time zone",
+            ),
+            (DataType::new("text", &[], None, &[None]), "text[]"),
+            (
+                DataType::new("int", &[], None, &[Some(3), Some(4)]),
+                "integer[]",
+            ),
+            (DataType::new("mood", &[], None, &[]), "mood"),
+        ];
+        for (data_type, expected) in cases {
+            assert_eq!(normalize_data_type(&data_type), expected, "{data_type}");
+        }
+    }
+}
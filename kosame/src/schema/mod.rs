@@ -0,0 +1,566 @@
+mod ddl;
+mod drift;
+
+pub use drift::*;
+
+use std::fmt::Write;
+
+use crate::expr::Expr;
+
+/// All tables of a database, as collected by `kosame::schema!`.
+pub struct Schema {
+    tables: &'static [&'static Table],
+}
+
+impl Schema {
+    pub const fn new(tables: &'static [&'static Table]) -> Self {
+        Self { tables }
+    }
+
+    #[inline]
+    pub const fn tables(&self) -> &'static [&'static Table] {
+        self.tables
+    }
+
+    /// Finds a table by name, optionally qualified by its schema, e.g. `analytics.page_views`.
+    pub fn table(&self, name: &str) -> Option<&'static Table> {
+        let (schema, name) = match name.split_once('.') {
+            Some((schema, name)) => (Some(schema), name),
+            None => (None, name),
+        };
+        self.tables
+            .iter()
+            .find(|table| {
+                table.name() == name && schema.is_none_or(|schema| table.schema() == Some(schema))
+            })
+            .copied()
+    }
+
+    /// Iterates over the relations of all tables, along with the table that declares them.
+    pub fn relations(&self) -> impl Iterator<Item = (&'static Table, &'static Relation)> {
+        self.tables.iter().flat_map(|table| {
+            table
+                .relations()
+                .iter()
+                .map(move |relation| (*table, *relation))
+        })
+    }
+}
+
+pub struct Table {
+    schema: Option<&'static str>,
+    name: &'static str,
+    kind: TableKind,
+    columns: &'static [&'static Column],
+    relations: &'static [&'static Relation],
+    constraints: &'static [Constraint],
+    indexes: &'static [Index],
+}
+
+impl Table {
+    pub const fn new(
+        schema: Option<&'static str>,
+        name: &'static str,
+        kind: TableKind,
+        columns: &'static [&'static Column],
+        relations: &'static [&'static Relation],
+        constraints: &'static [Constraint],
+        indexes: &'static [Index],
+    ) -> Self {
+        Self {
+            schema,
+            name,
+            kind,
+            columns,
+            relations,
+            constraints,
+            indexes,
+        }
+    }
+
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The database schema of the table, if its name is qualified, e.g. `analytics`.
+    #[inline]
+    pub const fn schema(&self) -> Option<&'static str> {
+        self.schema
+    }
+
+    #[inline]
+    pub const fn kind(&self) -> TableKind {
+        self.kind
+    }
+
+    #[inline]
+    pub const fn columns(&self) -> &'static [&'static Column] {
+        self.columns
+    }
+
+    #[inline]
+    pub const fn relations(&self) -> &'static [&'static Relation] {
+        self.relations
+    }
+
+    #[inline]
+    pub const fn constraints(&self) -> &'static [Constraint] {
+        self.constraints
+    }
+
+    #[inline]
+    pub const fn indexes(&self) -> &'static [Index] {
+        self.indexes
+    }
+
+    /// Whether lookups by the given columns are supported by an index, i.e. an index, primary key
+    /// or unique constraint starts with exactly these columns, in any order. Partial indexes are
+    /// not considered.
+    pub fn is_indexed(&self, columns: &[&Column]) -> bool {
+        let starts_with = |indexed: &[&Column]| {
+            indexed.len() >= columns.len()
+                && indexed[..columns.len()]
+                    .iter()
+                    .all(|indexed| columns.iter().any(|column| column.name() == indexed.name()))
+        };
+        self.indexes
+            .iter()
+            .filter(|index| index.predicate().is_none())
+            .any(|index| starts_with(index.columns()))
+            || self
+                .constraints
+                .iter()
+                .any(|constraint| match constraint.kind() {
+                    ConstraintKind::PrimaryKey { columns } | ConstraintKind::Unique { columns } => {
+                        starts_with(columns)
+                    }
+                    _ => false,
+                })
+    }
+
+    pub fn column(&self, name: &str) -> Option<&'static Column> {
+        self.columns
+            .iter()
+            .find(|column| column.name() == name)
+            .copied()
+    }
+
+    pub fn primary_key(&self) -> Option<&'static [&'static Column]> {
+        self.constraints
+            .iter()
+            .find_map(|constraint| match constraint.kind() {
+                ConstraintKind::PrimaryKey { columns } => Some(*columns),
+                _ => None,
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableKind {
+    Table,
+    View,
+    MaterializedView,
+}
+
+pub struct Column {
+    name: &'static str,
+    alias: Option<&'static str>,
+    data_type: DataType,
+    nullable: bool,
+    primary_key: bool,
+    default: Option<Expr>,
+    generated: Option<Generated>,
+}
+
+impl Column {
+    pub const fn new(
+        name: &'static str,
+        alias: Option<&'static str>,
+        data_type: DataType,
+        nullable: bool,
+        primary_key: bool,
+        default: Option<Expr>,
+        generated: Option<Generated>,
+    ) -> Self {
+        Self {
+            name,
+            alias,
+            data_type,
+            nullable,
+            primary_key,
+            default,
+            generated,
+        }
+    }
+
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[inline]
+    pub const fn alias(&self) -> Option<&'static str> {
+        self.alias
+    }
+
+    /// The SQL type of the column, regardless of any Rust type override.
+    #[inline]
+    pub const fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Whether the column may contain `null`. Primary key and identity columns never do.
+    #[inline]
+    pub const fn nullable(&self) -> bool {
+        self.nullable
+    }
+
+    /// Whether the column is part of the primary key, declared on the column or on the table.
+    #[inline]
+    pub const fn primary_key(&self) -> bool {
+        self.primary_key
+    }
+
+    /// The `default` expression of the column. Implicit defaults, like those of `serial` columns,
+    /// are not included.
+    #[inline]
+    pub const fn default(&self) -> Option<&Expr> {
+        self.default.as_ref()
+    }
+
+    #[inline]
+    pub const fn generated(&self) -> Option<&Generated> {
+        self.generated.as_ref()
+    }
+}
+
+/// A SQL data type as declared in `kosame::table!`, e.g. `timestamp(3) with time zone` or
+/// `text[]`.
+pub struct DataType {
+    name: &'static str,
+    params: &'static [u32],
+    time_zone: Option<bool>,
+    array_dimensions: &'static [Option<u32>],
+}
+
+impl DataType {
+    pub const fn new(
+        name: &'static str,
+        params: &'static [u32],
+        time_zone: Option<bool>,
+        array_dimensions: &'static [Option<u32>],
+    ) -> Self {
+        Self {
+            name,
+            params,
+            time_zone,
+            array_dimensions,
+        }
+    }
+
+    /// The name of the type as written, without parameters or time zone, e.g. `timestamp`.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The type parameters, e.g. `[10, 2]` for `numeric(10, 2)`.
+    #[inline]
+    pub const fn params(&self) -> &'static [u32] {
+        self.params
+    }
+
+    /// `Some(true)` for `with time zone`, `Some(false)` for `without time zone`.
+    #[inline]
+    pub const fn time_zone(&self) -> Option<bool> {
+        self.time_zone
+    }
+
+    /// One entry per array dimension, holding its declared size, if any.
+    #[inline]
+    pub const fn array_dimensions(&self) -> &'static [Option<u32>] {
+        self.array_dimensions
+    }
+}
+
+impl DataType {
+    /// Writes everything that follows the name of the type, e.g. `(3) with time zone`.
+    pub(crate) fn fmt_modifiers(&self, f: &mut impl Write) -> std::fmt::Result {
+        if !self.params.is_empty() {
+            f.write_str("(")?;
+            for (index, param) in self.params.iter().enumerate() {
+                write!(f, "{param}")?;
+                if index != self.params.len() - 1 {
+                    f.write_str(", ")?;
+                }
+            }
+            f.write_str(")")?;
+        }
+        match self.time_zone {
+            Some(true) => f.write_str(" with time zone")?,
+            Some(false) => f.write_str(" without time zone")?,
+            None => {}
+        }
+        for dimension in self.array_dimensions {
+            match dimension {
+                Some(size) => write!(f, "[{size}]")?,
+                None => f.write_str("[]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for DataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)?;
+        self.fmt_modifiers(f)
+    }
+}
+
+/// How the database generates the values of a column.
+pub enum Generated {
+    /// `generated always as (expr) stored`
+    Stored { expr: Expr },
+    /// `generated always as identity` or `generated by default as identity`
+    Identity { always: bool },
+}
+
+pub struct Relation {
+    name: &'static str,
+    source_table: &'static str,
+    source_columns: &'static [&'static Column],
+    target_table: &'static str,
+    target_columns: &'static [&'static Column],
+    junction: Option<Junction>,
+}
+
+impl Relation {
+    pub const fn new(
+        name: &'static str,
+        source_table: &'static str,
+        source_columns: &'static [&'static Column],
+        target_table: &'static str,
+        target_columns: &'static [&'static Column],
+        junction: Option<Junction>,
+    ) -> Self {
+        Self {
+            name,
+            source_table,
+            source_columns,
+            target_table,
+            target_columns,
+            junction,
+        }
+    }
+
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub const fn source_table(&self) -> &'static str {
+        self.source_table
+    }
+
+    pub const fn source_columns(&self) -> &'static [&'static Column] {
+        self.source_columns
+    }
+
+    pub const fn target_table(&self) -> &'static str {
+        self.target_table
+    }
+
+    pub const fn target_columns(&self) -> &'static [&'static Column] {
+        self.target_columns
+    }
+
+    pub const fn junction(&self) -> Option<&Junction> {
+        self.junction.as_ref()
+    }
+
+    /// Whether one of the target columns has the given name. Usable in constant expressions, so
+    /// that nested inserts can reject foreign key columns at compile time.
+    #[doc(hidden)]
+    pub const fn has_target_column(&self, name: &str) -> bool {
+        let mut index = 0;
+        while index < self.target_columns.len() {
+            if const_str_eq(self.target_columns[index].name, name) {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+
+    pub fn column_pairs(&self) -> impl Iterator<Item = (&Column, &Column)> {
+        self.source_columns
+            .iter()
+            .zip(self.target_columns)
+            .map(|(a, b)| (*a, *b))
+    }
+}
+
+const fn const_str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+pub struct Junction {
+    table: &'static Table,
+    source_columns: &'static [&'static Column],
+    target_columns: &'static [&'static Column],
+}
+
+impl Junction {
+    pub const fn new(
+        table: &'static Table,
+        source_columns: &'static [&'static Column],
+        target_columns: &'static [&'static Column],
+    ) -> Self {
+        Self {
+            table,
+            source_columns,
+            target_columns,
+        }
+    }
+
+    #[inline]
+    pub const fn table(&self) -> &'static Table {
+        self.table
+    }
+
+    pub const fn source_columns(&self) -> &'static [&'static Column] {
+        self.source_columns
+    }
+
+    pub const fn target_columns(&self) -> &'static [&'static Column] {
+        self.target_columns
+    }
+}
+
+pub struct Constraint {
+    name: Option<&'static str>,
+    kind: ConstraintKind,
+}
+
+impl Constraint {
+    pub const fn new(name: Option<&'static str>, kind: ConstraintKind) -> Self {
+        Self { name, kind }
+    }
+
+    #[inline]
+    pub const fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    #[inline]
+    pub const fn kind(&self) -> &ConstraintKind {
+        &self.kind
+    }
+}
+
+pub enum ConstraintKind {
+    PrimaryKey {
+        columns: &'static [&'static Column],
+    },
+    Unique {
+        columns: &'static [&'static Column],
+    },
+    Check {
+        expr: Expr,
+    },
+    ForeignKey {
+        columns: &'static [&'static Column],
+        target_schema: Option<&'static str>,
+        target_table: &'static str,
+        target_columns: &'static [&'static Column],
+        on_delete: ReferentialAction,
+        on_update: ReferentialAction,
+    },
+}
+
+pub struct Index {
+    name: Option<&'static str>,
+    unique: bool,
+    columns: &'static [&'static Column],
+    method: Option<&'static str>,
+    predicate: Option<Expr>,
+}
+
+impl Index {
+    pub const fn new(
+        name: Option<&'static str>,
+        unique: bool,
+        columns: &'static [&'static Column],
+        method: Option<&'static str>,
+        predicate: Option<Expr>,
+    ) -> Self {
+        Self {
+            name,
+            unique,
+            columns,
+            method,
+            predicate,
+        }
+    }
+
+    #[inline]
+    pub const fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    #[inline]
+    pub const fn unique(&self) -> bool {
+        self.unique
+    }
+
+    #[inline]
+    pub const fn columns(&self) -> &'static [&'static Column] {
+        self.columns
+    }
+
+    /// The index method given by `using`, e.g. `btree` or `gin`.
+    #[inline]
+    pub const fn method(&self) -> Option<&'static str> {
+        self.method
+    }
+
+    /// The `where` clause of a partial index.
+    #[inline]
+    pub const fn predicate(&self) -> Option<&Expr> {
+        self.predicate.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
+}
+
+impl ReferentialAction {
+    pub const fn as_sql(&self) -> &'static str {
+        match self {
+            Self::NoAction => "no action",
+            Self::Restrict => "restrict",
+            Self::Cascade => "cascade",
+            Self::SetNull => "set null",
+            Self::SetDefault => "set default",
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt::Write;
 
-use crate::query::BindParamOrdinal;
+use crate::{
+    query::BindParamOrdinal,
+    schema::{Column, Table},
+};
 
 pub trait Dialect {
     fn ident_esc() -> (&'static str, &'static str);
@@ -9,10 +12,44 @@ pub trait Dialect {
         name: &str,
         ordinal: BindParamOrdinal,
     ) -> std::fmt::Result;
+
+    /// Writes the data type of a column in a `create table` statement, along with the clause that
+    /// makes it a generated or identity column, if any. The output starts with a space.
+    fn fmt_column_type(formatter: &mut Formatter<'_, Self>, column: &Column) -> std::fmt::Result
+    where
+        Self: Sized;
+
+    /// Whether `create index` accepts an index without a name.
+    fn anonymous_indexes() -> bool;
+
+    /// Where the index method, e.g. `using btree`, goes in `create index`, or `None` if indexes
+    /// cannot choose their method, in which case it is left out.
+    fn index_method_position() -> Option<IndexMethodPosition>;
+
+    /// Whether foreign keys can be added to an existing table using `alter table`. Otherwise, the
+    /// database must accept foreign keys to tables that do not exist yet.
+    fn alter_table_add_constraint() -> bool;
+}
+
+pub enum IndexMethodPosition {
+    BeforeColumns,
+    AfterColumns,
+}
+
+/// Formats a statement into a new string, qualifying tables with the given runtime schema.
+pub(crate) fn to_sql_string<D: Dialect>(
+    schema: Option<&str>,
+    fmt_sql: impl FnOnce(&mut Formatter<D>) -> std::fmt::Result,
+) -> String {
+    let mut sql = String::new();
+    let mut formatter = Formatter::<D>::new(&mut sql).with_schema(schema);
+    fmt_sql(&mut formatter).expect("string formatting should never fail");
+    sql
 }
 
 pub struct Formatter<'a, D> {
     buf: &'a mut (dyn Write + 'a),
+    schema: Option<&'a str>,
     _dialect: std::marker::PhantomData<D>,
 }
 
@@ -23,13 +60,46 @@ where
     pub fn new(buf: &'a mut (dyn Write + 'a)) -> Self {
         Self {
             buf,
+            schema: None,
             _dialect: Default::default(),
         }
     }
 
+    /// Qualifies every table that is not declared with an explicit schema using the given schema,
+    /// e.g. to address the tables of one tenant in a schema-per-tenant database.
+    pub fn with_schema(mut self, schema: Option<&'a str>) -> Self {
+        self.schema = schema;
+        self
+    }
+
     pub fn write_ident(&mut self, ident: &str) -> std::fmt::Result {
+        // Identifiers may come from runtime values, such as a schema name, so a closing quote is
+        // escaped by doubling it.
         let (prefix, suffix) = D::ident_esc();
-        write!(self, "{prefix}{ident}{suffix}")
+        self.write_str(prefix)?;
+        for (index, part) in ident.split(suffix).enumerate() {
+            if index != 0 {
+                self.write_str(suffix)?;
+                self.write_str(suffix)?;
+            }
+            self.write_str(part)?;
+        }
+        self.write_str(suffix)
+    }
+
+    /// Writes the name of a table, qualified by its schema or the runtime schema, if any.
+    pub fn write_table_ident(&mut self, table: &Table) -> std::fmt::Result {
+        self.write_table_name(table.schema(), table.name())
+    }
+
+    /// Like [`Self::write_table_ident`], for tables that are only known by name, such as the
+    /// target of a foreign key.
+    pub fn write_table_name(&mut self, schema: Option<&str>, name: &str) -> std::fmt::Result {
+        if let Some(schema) = schema.or(self.schema) {
+            self.write_ident(schema)?;
+            self.write_str(".")?;
+        }
+        self.write_ident(name)
     }
 
     pub fn write_bind_param(&mut self, name: &str, ordinal: u32) -> Result<(), std::fmt::Error> {
@@ -0,0 +1,41 @@
+#[cfg(feature = "serde_json")]
+use std::ops::{Deref, DerefMut};
+
+/// A PostgreSQL `interval`, stored the same way the database stores it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde-full", derive(serde::Deserialize))]
+pub struct Interval {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+/// A `json` or `jsonb` value decoded into, or encoded from, any serde type.
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Json<T>(pub T);
+
+#[cfg(feature = "serde_json")]
+impl<T> Json<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut <Self as Deref>::Target {
+        &mut self.0
+    }
+}
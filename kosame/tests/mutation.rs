@@ -0,0 +1,194 @@
+#![cfg(feature = "dbms-postgres")]
+
+use kosame::{mutation::Mutation, postgres::Dialect};
+
+mod schema {
+    kosame::table! {
+        create table posts (
+            id serial primary key,
+            title text not null,
+            content text,
+        );
+
+        comments: (id) <= comments (post_id),
+    }
+
+    kosame::table! {
+        create table comments (
+            id serial primary key,
+            post_id int not null references posts (id),
+            content text not null,
+            upvotes int not null default 0,
+        );
+
+        post: (post_id) => posts (id),
+    }
+}
+
+kosame::insert! {
+    insert into schema::posts (title, content)
+    values (:title, :content)
+    as create_post
+}
+
+#[test]
+fn insert() {
+    let title = "my post";
+    let mutation = kosame::insert! {
+        insert into schema::posts (title, content)
+        values (:title, null), ("another post", "hello")
+    };
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        r#"insert into "posts" ("title", "content") values ($1, null), ('another post', 'hello')"#
+    );
+}
+
+#[test]
+fn insert_named() {
+    let mutation = create_post::Mutation::new(create_post::Params {
+        title: &"my post",
+        content: &"hello",
+    });
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        r#"insert into "posts" ("title", "content") values ($1, $2)"#
+    );
+}
+
+#[test]
+fn update() {
+    let id = 5;
+    let content = "edited";
+    let mutation = kosame::update! {
+        update schema::comments
+        set upvotes = upvotes + 1, content = :content
+        where id = :id and post_id is not null
+    };
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        r#"update "comments" set "upvotes" = "upvotes" + 1, "content" = $1 where "id" = $2 and "post_id" is not null"#
+    );
+}
+
+#[test]
+fn delete() {
+    let post_id = 5;
+    let mutation = kosame::delete! {
+        delete from schema::comments
+        where post_id = :post_id
+    };
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        r#"delete from "comments" where "post_id" = $1"#
+    );
+
+    let mutation = kosame::delete! {
+        delete from schema::comments
+        where true
+    };
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        r#"delete from "comments" where true"#
+    );
+}
+
+kosame::update! {
+    update schema::comments
+    set upvotes = upvotes + 1
+    where id = :id
+    returning {
+        id,
+        content as body,
+        upvotes > 10 as popular: bool,
+    }
+    as upvote_comment
+}
+
+// The rows of inline mutations are never decoded here, so their fields are never read.
+#[allow(dead_code)]
+#[test]
+fn returning() {
+    let id = 5;
+    let mutation = upvote_comment::Mutation::new(upvote_comment::Params { id: &id });
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        r#"update "comments" set "upvotes" = "upvotes" + 1 where "id" = $1 returning "id", "content", "upvotes" > 10"#
+    );
+
+    let row = upvote_comment::Row {
+        id: 5,
+        body: "hello".to_string(),
+        popular: false,
+    };
+    assert_eq!(row.body, "hello");
+
+    let mutation = kosame::insert! {
+        insert into schema::posts (title)
+        values ("my post")
+        returning { * }
+    };
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        r#"insert into "posts" ("title") values ('my post') returning "id", "title", "content""#
+    );
+
+    let mutation = kosame::delete! {
+        delete from schema::comments
+        where true
+        returning { id }
+    };
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        r#"delete from "comments" where true returning "id""#
+    );
+}
+
+#[test]
+fn on_conflict() {
+    let id = 5;
+    let title = "my post";
+    let mutation = kosame::insert! {
+        insert into schema::posts (id, title)
+        values (:id, :title)
+        on conflict (id) do update
+        set title = excluded.title, content = posts.content
+        where posts.content is null
+    };
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        r#"insert into "posts" ("id", "title") values ($1, $2) on conflict ("id") do update set "title" = "excluded"."title", "content" = "posts"."content" where "posts"."content" is null"#
+    );
+
+    let mutation = kosame::insert! {
+        insert into schema::posts (id, title)
+        values (:id, :title)
+        on conflict (id) do nothing
+    };
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        r#"insert into "posts" ("id", "title") values ($1, $2) on conflict ("id") do nothing"#
+    );
+}
+
+#[test]
+fn nested_insert() {
+    let title = "my post";
+    let mutation = kosame::insert! {
+        insert into schema::posts (title)
+        values (:title)
+        comments {
+            (content) values ("first"), ("second")
+        }
+        returning { id }
+    };
+    assert_eq!(
+        mutation.to_sql::<Dialect>(),
+        concat!(
+            r#"with "insert" as (insert into "posts" ("title") values ($1) returning *), "#,
+            r#""insert_0" as (insert into "comments" ("post_id", "content") values "#,
+            r#"((select "id" from "insert"), 'first'), ((select "id" from "insert"), 'second')) "#,
+            r#"select "id" from "insert" as "posts""#,
+        )
+    );
+}
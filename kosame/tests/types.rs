@@ -0,0 +1,187 @@
+#![cfg(feature = "postgres-types")]
+
+use kosame::driver::postgres_types::{
+    BytesMut, FromSql, Kind, ToSql, Type, record_field_from_sql, record_field_to_sql,
+};
+use postgres_types::Field;
+
+kosame::enum_type! {
+    create type mood as enum ("happy", "sad");
+}
+
+kosame::composite_type! {
+    create type entry as (title text, moods mood[]);
+
+    use mood;
+}
+
+kosame::domain! {
+    create domain email as text not null check (length(value) > 3);
+}
+
+fn mood_type() -> Type {
+    Type::new(
+        "mood".to_string(),
+        90001,
+        Kind::Enum(vec!["happy".to_string(), "sad".to_string()]),
+        "public".to_string(),
+    )
+}
+
+fn mood_array_type() -> Type {
+    Type::new(
+        "_mood".to_string(),
+        90002,
+        Kind::Array(mood_type()),
+        "public".to_string(),
+    )
+}
+
+fn entry_type(attributes: &[&str]) -> Type {
+    let fields = attributes
+        .iter()
+        .map(|attribute| match *attribute {
+            "title" => Field::new("title".to_string(), Type::TEXT),
+            "moods" => Field::new("moods".to_string(), mood_array_type()),
+            _ => unreachable!(),
+        })
+        .collect();
+    Type::new(
+        "entry".to_string(),
+        90003,
+        Kind::Composite(fields),
+        "public".to_string(),
+    )
+}
+
+fn entry() -> Entry {
+    Entry {
+        title: Some("monday".to_string()),
+        moods: Some(vec![Some(Mood::Sad), None]),
+    }
+}
+
+#[test]
+fn enum_round_trip() {
+    let mut buf = BytesMut::new();
+    Mood::Happy.to_sql(&mood_type(), &mut buf).unwrap();
+    assert_eq!(&buf[..], b"happy");
+    assert_eq!(Mood::from_sql(&mood_type(), &buf).unwrap(), Mood::Happy);
+}
+
+#[test]
+fn enum_unknown_label() {
+    let error = Mood::from_sql(&mood_type(), b"angry").unwrap_err();
+    assert_eq!(error.to_string(), "unknown label for enum type `mood`");
+}
+
+#[test]
+fn composite_round_trip() {
+    let ty = entry_type(&["title", "moods"]);
+    let mut buf = BytesMut::new();
+    entry().to_sql(&ty, &mut buf).unwrap();
+    assert_eq!(Entry::from_sql(&ty, &buf).unwrap(), entry());
+
+    // Inside records, only the attribute count is known.
+    assert_eq!(Entry::from_sql(&Type::RECORD, &buf).unwrap(), entry());
+}
+
+#[test]
+fn composite_null_attributes() {
+    let ty = entry_type(&["title", "moods"]);
+    let mut buf = BytesMut::new();
+    Entry::default().to_sql(&ty, &mut buf).unwrap();
+    assert_eq!(Entry::from_sql(&ty, &buf).unwrap(), Entry::default());
+}
+
+#[test]
+fn composite_reordered_attributes() {
+    // Encoding goes by attribute name, but decoding goes by position.
+    let ty = entry_type(&["moods", "title"]);
+    let mut buf = BytesMut::new();
+    entry().to_sql(&ty, &mut buf).unwrap();
+
+    let error = Entry::from_sql(&ty, &buf).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "attributes of composite type `entry` do not match its declaration"
+    );
+}
+
+#[test]
+fn composite_missing_attribute() {
+    let ty = entry_type(&["title"]);
+    let mut buf = BytesMut::new();
+    entry().to_sql(&ty, &mut buf).unwrap();
+
+    let error = Entry::from_sql(&Type::RECORD, &buf).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected 2 attributes for composite type `entry`, got 1"
+    );
+}
+
+#[test]
+fn composite_truncated() {
+    let ty = entry_type(&["title", "moods"]);
+    let mut buf = BytesMut::new();
+    entry().to_sql(&ty, &mut buf).unwrap();
+
+    let error = Entry::from_sql(&ty, &buf[..2]).unwrap_err();
+    assert_eq!(error.to_string(), "unexpected end of composite value");
+
+    let error = Entry::from_sql(&ty, &buf[..buf.len() - 1]).unwrap_err();
+    assert_eq!(error.to_string(), "unexpected end of record");
+}
+
+#[test]
+fn domain_round_trip() {
+    let ty = Type::new(
+        "email".to_string(),
+        90004,
+        Kind::Domain(Type::TEXT),
+        "public".to_string(),
+    );
+    let value = Email("kosame@example.com".to_string());
+    let mut buf = BytesMut::new();
+    value.to_sql(&ty, &mut buf).unwrap();
+    assert_eq!(Email::from_sql(&ty, &buf).unwrap(), value);
+
+    // Query results report the base type instead of the domain.
+    assert_eq!(Email::from_sql(&Type::TEXT, &buf).unwrap(), value);
+}
+
+#[test]
+fn enum_array_in_record() {
+    let value = vec![Some(Mood::Happy), None, Some(Mood::Sad)];
+    let mut buf = BytesMut::new();
+    record_field_to_sql(&value, &mood_array_type(), &mut buf).unwrap();
+
+    // Records only carry the OID of the array type, which is not known to the driver.
+    let mut offset = 0;
+    let decoded: Vec<Option<Mood>> = record_field_from_sql(&buf, &mut offset).unwrap();
+    assert_eq!(decoded, value);
+    assert_eq!(offset, buf.len());
+}
+
+#[test]
+fn empty_enum_array_in_record() {
+    let value: Vec<Option<Mood>> = vec![];
+    let mut buf = BytesMut::new();
+    record_field_to_sql(&value, &mood_array_type(), &mut buf).unwrap();
+
+    let mut offset = 0;
+    let decoded: Vec<Option<Mood>> = record_field_from_sql(&buf, &mut offset).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn builtin_array_in_record() {
+    let value = vec![Some(1), None, Some(3)];
+    let mut buf = BytesMut::new();
+    record_field_to_sql(&value, &Type::INT4_ARRAY, &mut buf).unwrap();
+
+    let mut offset = 0;
+    let decoded: Vec<Option<i32>> = record_field_from_sql(&buf, &mut offset).unwrap();
+    assert_eq!(decoded, value);
+}
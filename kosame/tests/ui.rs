@@ -0,0 +1,10 @@
+#![cfg(feature = "dbms-postgres")]
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+
+    #[cfg(not(feature = "rust_decimal"))]
+    cases.compile_fail("tests/ui/features/numeric_without_rust_decimal.rs");
+}
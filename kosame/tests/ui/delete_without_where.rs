@@ -0,0 +1,12 @@
+kosame::table! {
+    create table posts (
+        id serial primary key,
+        title text not null,
+    );
+}
+
+fn main() {
+    kosame::delete! {
+        delete from posts
+    };
+}
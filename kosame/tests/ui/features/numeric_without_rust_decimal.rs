@@ -0,0 +1,8 @@
+kosame::table! {
+    create table accounts (
+        id serial primary key,
+        balance numeric(10, 2) not null,
+    );
+}
+
+fn main() {}
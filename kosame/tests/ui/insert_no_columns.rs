@@ -0,0 +1,13 @@
+kosame::table! {
+    create table posts (
+        id serial primary key,
+        title text not null,
+    );
+}
+
+fn main() {
+    kosame::insert! {
+        insert into posts ()
+        values ()
+    };
+}
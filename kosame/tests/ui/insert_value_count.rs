@@ -0,0 +1,14 @@
+kosame::table! {
+    create table posts (
+        id serial primary key,
+        title text not null,
+        content text,
+    );
+}
+
+fn main() {
+    kosame::insert! {
+        insert into posts (title, content)
+        values ("my post"), ("another post", "hello", "again")
+    };
+}
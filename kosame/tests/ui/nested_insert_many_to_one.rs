@@ -0,0 +1,26 @@
+kosame::table! {
+    create table posts (
+        id serial primary key,
+        title text not null,
+    );
+}
+
+kosame::table! {
+    create table comments (
+        id serial primary key,
+        post_id int not null,
+        body text not null,
+    );
+
+    post: (post_id) => posts (id),
+}
+
+fn main() {
+    kosame::insert! {
+        insert into comments (post_id, body)
+        values (1, "hello")
+        post {
+            (title) values ("my post")
+        }
+    };
+}
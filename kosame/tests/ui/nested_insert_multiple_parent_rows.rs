@@ -0,0 +1,26 @@
+kosame::table! {
+    create table posts (
+        id serial primary key,
+        title text not null,
+    );
+
+    comments: (id) <= comments (post_id),
+}
+
+kosame::table! {
+    create table comments (
+        id serial primary key,
+        post_id int not null,
+        body text not null,
+    );
+}
+
+fn main() {
+    kosame::insert! {
+        insert into posts (title)
+        values ("my post"), ("another post")
+        comments {
+            (body) values ("hello")
+        }
+    };
+}
@@ -0,0 +1,15 @@
+kosame::table! {
+    create table posts (
+        id serial primary key,
+        title text not null,
+    );
+}
+
+fn main() {
+    kosame::insert! {
+        insert into posts (id, title)
+        values (1, "my post")
+        on conflict (id) do update
+        set title = proposed.title
+    };
+}
@@ -0,0 +1,17 @@
+kosame::table! {
+    create table posts (
+        id serial primary key,
+        title text not null,
+        content text,
+    );
+}
+
+fn main() {
+    kosame::insert! {
+        insert into posts (id, title)
+        values (1, "my post")
+        on conflict (id) do update
+        set title = title
+        where content is null
+    };
+}
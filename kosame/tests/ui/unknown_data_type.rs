@@ -0,0 +1,8 @@
+kosame::table! {
+    create table posts (
+        id serial primary key,
+        title txet not null,
+    );
+}
+
+fn main() {}
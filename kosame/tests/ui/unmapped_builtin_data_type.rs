@@ -0,0 +1,8 @@
+kosame::table! {
+    create table accounts (
+        id serial primary key,
+        balance money not null,
+    );
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+kosame::table! {
+    create table posts (
+        id serial primary key,
+        title text not null,
+    );
+}
+
+fn main() {
+    kosame::update! {
+        update posts
+        set title = "a", title = "b"
+        where id = 1
+    };
+}
@@ -1,19 +1,20 @@
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{
-    Ident, Token, parenthesized,
+    Token, parenthesized,
     parse::{Parse, ParseStream},
     token::Paren,
 };
 
 use super::{Expr, Visitor};
+use crate::schema::data_type::DataType;
 
 pub struct Cast {
     _cast: kw::cast,
     _paren: Paren,
     value: Box<Expr>,
     _as: Token![as],
-    data_type: Ident,
+    data_type: DataType,
 }
 
 impl Cast {
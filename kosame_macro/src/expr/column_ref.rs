@@ -1,22 +1,104 @@
 use super::Visitor;
+use proc_macro_error::emit_error;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{
-    Ident,
+    Ident, Token,
     parse::{Parse, ParseStream},
 };
 
 pub struct ColumnRef {
+    correlation: Option<(Ident, Token![.])>,
     name: Ident,
 }
 
 impl ColumnRef {
-    pub fn accept<'a>(&'a self, _visitor: &mut impl Visitor<'a>) {}
+    pub fn correlation(&self) -> Option<&Ident> {
+        self.correlation
+            .as_ref()
+            .map(|(correlation, _)| correlation)
+    }
+
+    pub fn accept<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        visitor.visit_column_ref(self);
+    }
+}
+
+/// Emits an error for every column reference qualified with a name other than the allowed ones.
+/// Qualified column references are only meaningful in `on conflict ... do update`, where they may
+/// refer to `excluded` or the target table.
+pub struct CorrelationCheck {
+    allowed: Vec<String>,
+    required: bool,
+}
+
+impl CorrelationCheck {
+    pub fn new(allowed: Vec<String>) -> Self {
+        Self {
+            allowed,
+            required: false,
+        }
+    }
+
+    /// Like [`CorrelationCheck::new`], but also emits an error for unqualified column references.
+    /// Inside `on conflict ... do update`, PostgreSQL considers them ambiguous.
+    pub fn required(allowed: Vec<String>) -> Self {
+        Self {
+            allowed,
+            required: true,
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for CorrelationCheck {
+    fn visit_column_ref(&mut self, column_ref: &'a ColumnRef) {
+        let Some(correlation) = column_ref.correlation() else {
+            if self.required {
+                emit_error!(
+                    column_ref.name.span(),
+                    "column `{}` must be qualified in `on conflict ... do update`",
+                    column_ref.name;
+                    help = "use {} to refer to the existing or the proposed row",
+                    self.allowed
+                        .iter()
+                        .map(|allowed| format!("`{allowed}.{}`", column_ref.name))
+                        .collect::<Vec<_>>()
+                        .join(" or ")
+                );
+            }
+            return;
+        };
+        if self.allowed.iter().any(|allowed| correlation == allowed) {
+            return;
+        }
+        if self.allowed.is_empty() {
+            emit_error!(
+                correlation.span(),
+                "qualified column references are only allowed in `on conflict ... do update`"
+            );
+        } else {
+            emit_error!(
+                correlation.span(),
+                "unknown correlation `{}`, expected one of: {}",
+                correlation,
+                self.allowed
+                    .iter()
+                    .map(|allowed| format!("`{allowed}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
 }
 
 impl Parse for ColumnRef {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
+            correlation: if input.peek2(Token![.]) {
+                Some((input.parse()?, input.parse()?))
+            } else {
+                None
+            },
             name: input.parse()?,
         })
     }
@@ -25,8 +107,16 @@ impl Parse for ColumnRef {
 impl ToTokens for ColumnRef {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let name = &self.name;
+        let correlation = match &self.correlation {
+            Some((correlation, _)) => {
+                let correlation = correlation.to_string();
+                quote! { Some(#correlation) }
+            }
+            None => quote! { None },
+        };
         quote! {
             ::kosame::expr::ColumnRef::new(
+                #correlation,
                 &scope::columns::#name::COLUMN
             )
         }
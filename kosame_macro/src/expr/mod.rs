@@ -13,7 +13,7 @@ pub use binary::{Associativity, BinOp, Binary};
 pub use bind_param::BindParam;
 pub use call::Call;
 pub use cast::Cast;
-pub use column_ref::ColumnRef;
+pub use column_ref::{ColumnRef, CorrelationCheck};
 pub use lit::Lit;
 pub use paren::Paren;
 pub use unary::Unary;
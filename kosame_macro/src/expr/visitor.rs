@@ -1,5 +1,6 @@
-use super::BindParam;
+use super::{BindParam, ColumnRef};
 
 pub trait Visitor<'a> {
     fn visit_bind_param(&mut self, _bind_param: &'a BindParam) {}
+    fn visit_column_ref(&mut self, _column_ref: &'a ColumnRef) {}
 }
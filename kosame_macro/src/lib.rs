@@ -1,6 +1,7 @@
 pub(crate) mod alias;
 pub(crate) mod docs;
 pub(crate) mod expr;
+pub(crate) mod mutation;
 pub(crate) mod path_ext;
 pub(crate) mod query;
 pub(crate) mod row_struct;
@@ -18,6 +19,34 @@ pub fn table(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     quote! { #input }.into()
 }
 
+#[proc_macro_error]
+#[proc_macro]
+pub fn schema(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as schema::registry::Schema);
+    quote! { #input }.into()
+}
+
+#[proc_macro_error]
+#[proc_macro]
+pub fn enum_type(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as schema::enum_type::EnumType);
+    quote! { #input }.into()
+}
+
+#[proc_macro_error]
+#[proc_macro]
+pub fn composite_type(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as schema::composite_type::CompositeType);
+    quote! { #input }.into()
+}
+
+#[proc_macro_error]
+#[proc_macro]
+pub fn domain(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as schema::domain::Domain);
+    quote! { #input }.into()
+}
+
 #[proc_macro_error]
 #[proc_macro]
 pub fn query(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -25,6 +54,27 @@ pub fn query(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     quote! { #input }.into()
 }
 
+#[proc_macro_error]
+#[proc_macro]
+pub fn insert(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as mutation::Mutation<mutation::Insert>);
+    quote! { #input }.into()
+}
+
+#[proc_macro_error]
+#[proc_macro]
+pub fn update(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as mutation::Mutation<mutation::Update>);
+    quote! { #input }.into()
+}
+
+#[proc_macro_error]
+#[proc_macro]
+pub fn delete(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as mutation::Mutation<mutation::Delete>);
+    quote! { #input }.into()
+}
+
 #[proc_macro_error]
 #[proc_macro_derive(Row, attributes(star))]
 pub fn derive_row(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
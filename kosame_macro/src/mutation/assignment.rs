@@ -0,0 +1,86 @@
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::expr::{Expr, Visitor};
+
+mod kw {
+    use syn::custom_keyword;
+
+    custom_keyword!(set);
+}
+
+pub struct Set {
+    _set: kw::set,
+    assignments: Punctuated<Assignment, Token![,]>,
+}
+
+impl Set {
+    pub fn accept_expr<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        for assignment in &self.assignments {
+            assignment.expr.accept(visitor);
+        }
+    }
+}
+
+impl Parse for Set {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let result = Self {
+            _set: input.parse()?,
+            assignments: Punctuated::parse_separated_nonempty(input)?,
+        };
+
+        let mut existing = vec![];
+        for assignment in &result.assignments {
+            if existing.contains(&&assignment.column) {
+                emit_error!(
+                    assignment.column.span(),
+                    "duplicate assignment to column `{}`",
+                    assignment.column
+                );
+            }
+            existing.push(&assignment.column);
+        }
+
+        Ok(result)
+    }
+}
+
+impl ToTokens for Set {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let assignments = self.assignments.iter();
+        quote! { &[#(#assignments),*] }.to_tokens(tokens);
+    }
+}
+
+pub struct Assignment {
+    column: Ident,
+    _eq: Token![=],
+    expr: Expr,
+}
+
+impl Parse for Assignment {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            column: input.parse()?,
+            _eq: input.parse()?,
+            expr: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Assignment {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let column = &self.column;
+        let expr = &self.expr;
+        quote! {
+            ::kosame::mutation::Assignment::new(&scope::columns::#column::COLUMN, #expr)
+        }
+        .to_tokens(tokens);
+    }
+}
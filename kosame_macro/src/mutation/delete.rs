@@ -0,0 +1,86 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+use super::{Returning, Statement};
+use crate::{expr::Visitor, path_ext::PathExt, query::filter::Filter};
+
+mod kw {
+    use syn::custom_keyword;
+
+    custom_keyword!(delete);
+    custom_keyword!(from);
+}
+
+pub struct Delete {
+    _delete: kw::delete,
+    _from: kw::from,
+    table: syn::Path,
+    filter: Filter,
+    returning: Option<Returning>,
+}
+
+impl Statement for Delete {
+    fn table(&self) -> &syn::Path {
+        &self.table
+    }
+
+    fn returning(&self) -> Option<&Returning> {
+        self.returning.as_ref()
+    }
+
+    fn accept_expr<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        self.filter.expr().accept(visitor);
+
+        if let Some(returning) = &self.returning {
+            returning.accept_expr(visitor);
+        }
+    }
+
+    fn to_statement_tokens(&self, tokens: &mut TokenStream) {
+        let table_path = self.table.to_call_site(1);
+        let filter = self.filter.expr();
+
+        let returning = match &self.returning {
+            Some(returning) => quote! { Some(#returning) },
+            None => quote! { None },
+        };
+
+        quote! {
+            ::kosame::mutation::Statement::Delete(::kosame::mutation::Delete::new(
+                &#table_path::TABLE,
+                Some(#filter),
+                #returning,
+            ))
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl Parse for Delete {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _delete = input.parse()?;
+        let _from = input.parse()?;
+        let table: syn::Path = input.parse()?;
+
+        // Deleting every row of a table is almost never intended, so we require the caller to
+        // opt in explicitly by writing `where true`.
+        if !Filter::peek(input) {
+            return Err(syn::Error::new(
+                table.span(),
+                "delete requires a `where` clause, use `where true` to delete all rows",
+            ));
+        }
+
+        Ok(Self {
+            _delete,
+            _from,
+            table,
+            filter: input.parse()?,
+            returning: input.call(Returning::parse_optional)?,
+        })
+    }
+}
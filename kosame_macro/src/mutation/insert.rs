@@ -0,0 +1,355 @@
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{
+    Ident, Token, braced, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+use super::{Returning, Statement, on_conflict::OnConflict};
+use crate::{
+    expr::{CorrelationCheck, Expr, Visitor},
+    path_ext::PathExt,
+};
+
+mod kw {
+    use syn::custom_keyword;
+
+    custom_keyword!(insert);
+    custom_keyword!(into);
+    custom_keyword!(values);
+}
+
+pub struct Insert {
+    _insert: kw::insert,
+    _into: kw::into,
+    table: syn::Path,
+    _paren: syn::token::Paren,
+    columns: Punctuated<Ident, Token![,]>,
+    _values: kw::values,
+    values: Punctuated<InsertValues, Token![,]>,
+    on_conflict: Option<OnConflict>,
+    nested: Vec<NestedInsert>,
+    returning: Option<Returning>,
+}
+
+impl Statement for Insert {
+    fn table(&self) -> &syn::Path {
+        &self.table
+    }
+
+    fn returning(&self) -> Option<&Returning> {
+        self.returning.as_ref()
+    }
+
+    fn accept_expr<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        for values in &self.values {
+            for expr in &values.exprs {
+                expr.accept(visitor);
+            }
+        }
+
+        if let Some(on_conflict) = &self.on_conflict {
+            on_conflict.accept_expr(visitor);
+        }
+
+        for nested in &self.nested {
+            nested.accept_expr(visitor);
+        }
+
+        if let Some(returning) = &self.returning {
+            returning.accept_expr(visitor);
+        }
+    }
+
+    fn check_correlations(&self) {
+        let mut check = CorrelationCheck::new(vec![]);
+        for values in &self.values {
+            for expr in &values.exprs {
+                expr.accept(&mut check);
+            }
+        }
+
+        for nested in &self.nested {
+            nested.accept_expr(&mut check);
+        }
+
+        if let Some(returning) = &self.returning {
+            returning.accept_expr(&mut check);
+        }
+
+        // Inside `on conflict ... do update`, columns must refer to the existing row by the name
+        // of the table, or to the proposed row as `excluded.column`.
+        if let Some(on_conflict) = &self.on_conflict {
+            let mut allowed = vec![];
+            if let Some(segment) = self.table.segments.last() {
+                allowed.push(segment.ident.to_string());
+            }
+            allowed.push("excluded".to_string());
+            on_conflict.accept_expr(&mut CorrelationCheck::required(allowed));
+        }
+    }
+
+    fn to_statement_tokens(&self, tokens: &mut TokenStream) {
+        let table_path = self.table.to_call_site(1);
+        let columns = self.columns.iter();
+        let values = self.values.iter();
+
+        let on_conflict = match &self.on_conflict {
+            Some(on_conflict) => quote! { Some(#on_conflict) },
+            None => quote! { None },
+        };
+
+        let nested = self
+            .nested
+            .iter()
+            .map(|nested| nested.to_token_stream(&table_path));
+
+        let returning = match &self.returning {
+            Some(returning) => quote! { Some(#returning) },
+            None => quote! { None },
+        };
+
+        quote! {
+            ::kosame::mutation::Statement::Insert(::kosame::mutation::Insert::new(
+                &#table_path::TABLE,
+                &[#(&#table_path::columns::#columns::COLUMN),*],
+                &[#(#values),*],
+                #on_conflict,
+                &[#(#nested),*],
+                #returning,
+            ))
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl Parse for Insert {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let result = Self {
+            _insert: input.parse()?,
+            _into: input.parse()?,
+            table: input.parse()?,
+            _paren: parenthesized!(content in input),
+            columns: content.parse_terminated(Ident::parse, Token![,])?,
+            _values: input.parse()?,
+            values: Punctuated::parse_separated_nonempty(input)?,
+            on_conflict: input.call(OnConflict::parse_optional)?,
+            nested: input.call(NestedInsert::parse_many)?,
+            returning: input.call(Returning::parse_optional)?,
+        };
+
+        check_columns_and_values(
+            &result._paren,
+            &result.columns,
+            &result.values,
+            &result.nested,
+        );
+
+        // If the parent row conflicts, the parent insert returns no key, and the children would
+        // be inserted with a `null` foreign key.
+        if let Some(nested) = result.nested.first()
+            && result.on_conflict.is_some()
+        {
+            emit_error!(
+                nested.relation.span(),
+                "nested inserts cannot be combined with `on conflict`"
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+pub struct NestedInsert {
+    relation: Ident,
+    _brace: syn::token::Brace,
+    _paren: syn::token::Paren,
+    columns: Punctuated<Ident, Token![,]>,
+    _values: kw::values,
+    values: Punctuated<InsertValues, Token![,]>,
+    nested: Vec<NestedInsert>,
+}
+
+impl NestedInsert {
+    fn parse_many(input: ParseStream) -> syn::Result<Vec<Self>> {
+        let mut result: Vec<Self> = vec![];
+        while !Returning::peek(input) && input.peek(Ident) && input.peek2(syn::token::Brace) {
+            let nested: Self = input.parse()?;
+            if result
+                .iter()
+                .any(|existing| existing.relation == nested.relation)
+            {
+                emit_error!(
+                    nested.relation.span(),
+                    "duplicate nested insert for relation `{}`",
+                    nested.relation
+                );
+            }
+            result.push(nested);
+        }
+        Ok(result)
+    }
+
+    fn accept_expr<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        for values in &self.values {
+            for expr in &values.exprs {
+                expr.accept(visitor);
+            }
+        }
+
+        for nested in &self.nested {
+            nested.accept_expr(visitor);
+        }
+    }
+
+    fn to_token_stream(&self, parent_table_path: &impl ToTokens) -> TokenStream {
+        let relation = &self.relation;
+        let relation_path = quote! { #parent_table_path::relations::#relation };
+        let table_path = quote! { #relation_path::target_table };
+        let columns = self.columns.iter();
+        let values = self.values.iter();
+        let nested = self
+            .nested
+            .iter()
+            .map(|nested| nested.to_token_stream(&table_path));
+
+        // Child rows can only be inserted through relations pointing to them.
+        let assert_one_to_many = quote_spanned! {relation.span()=>
+            const _: () = {
+                type Relation = #relation_path::Type<()>;
+                const fn assert_one_to_many<T: ::kosame::relation::NestedInsertRelation>() {}
+                assert_one_to_many::<Relation>();
+            };
+            const _: () = assert!(
+                #relation_path::RELATION.junction().is_none(),
+                "nested inserts through many-to-many relations are not supported",
+            );
+        };
+
+        // The foreign key columns are filled in from the parent row.
+        let assert_no_foreign_keys = self.columns.iter().map(|column| {
+            let column_string = column.to_string();
+            let message = format!(
+                "column `{column}` is filled in from the parent row by the nested insert through `{relation}`, remove it from the column list"
+            );
+            quote_spanned! {column.span()=>
+                const _: () = assert!(
+                    !#relation_path::RELATION.has_target_column(#column_string),
+                    #message,
+                );
+            }
+        });
+
+        quote! {
+            {
+                #assert_one_to_many
+                #(#assert_no_foreign_keys)*
+
+                ::kosame::mutation::NestedInsert::new(
+                    &#relation_path::RELATION,
+                    &#table_path::TABLE,
+                    &[#(&#table_path::columns::#columns::COLUMN),*],
+                    &[#(#values),*],
+                    &[#(#nested),*],
+                )
+            }
+        }
+    }
+}
+
+impl Parse for NestedInsert {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let brace_content;
+        let content;
+        let result = Self {
+            relation: input.parse()?,
+            _brace: braced!(brace_content in input),
+            _paren: parenthesized!(content in brace_content),
+            columns: content.parse_terminated(Ident::parse, Token![,])?,
+            _values: brace_content.parse()?,
+            values: Punctuated::parse_separated_nonempty(&brace_content)?,
+            nested: brace_content.call(NestedInsert::parse_many)?,
+        };
+
+        if !brace_content.is_empty() {
+            return Err(brace_content.error("unexpected tokens in nested insert"));
+        }
+
+        check_columns_and_values(
+            &result._paren,
+            &result.columns,
+            &result.values,
+            &result.nested,
+        );
+
+        Ok(result)
+    }
+}
+
+fn check_columns_and_values(
+    paren: &syn::token::Paren,
+    columns: &Punctuated<Ident, Token![,]>,
+    values: &Punctuated<InsertValues, Token![,]>,
+    nested: &[NestedInsert],
+) {
+    if columns.is_empty() {
+        emit_error!(
+            paren.span.span(),
+            "at least one column must be specified for insert"
+        );
+    }
+
+    let mut existing = vec![];
+    for column in columns {
+        if existing.contains(&column) {
+            emit_error!(column.span(), "duplicate column `{}`", column);
+        }
+        existing.push(column);
+    }
+
+    for row in values {
+        if row.exprs.len() != columns.len() {
+            emit_error!(
+                row._paren.span.span(),
+                "expected {} values to match the number of columns, found {}",
+                columns.len(),
+                row.exprs.len()
+            );
+        }
+    }
+
+    if let Some(nested) = nested.first()
+        && values.len() != 1
+    {
+        emit_error!(
+            nested.relation.span(),
+            "nested inserts require exactly one row of values in the parent insert"
+        );
+    }
+}
+
+pub struct InsertValues {
+    _paren: syn::token::Paren,
+    exprs: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for InsertValues {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            _paren: parenthesized!(content in input),
+            exprs: content.parse_terminated(Expr::parse, Token![,])?,
+        })
+    }
+}
+
+impl ToTokens for InsertValues {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let exprs = self.exprs.iter();
+        quote! { &[#(#exprs),*] }.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,163 @@
+mod assignment;
+mod delete;
+mod insert;
+mod on_conflict;
+mod returning;
+mod update;
+
+pub use delete::Delete;
+pub use insert::Insert;
+pub use update::Update;
+
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use returning::Returning;
+use syn::{
+    Attribute, Ident,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{
+    alias::Alias,
+    expr::{CorrelationCheck, Visitor},
+    path_ext::PathExt,
+    query::bind_params::BindParamsBuilder,
+};
+
+pub trait Statement: Parse {
+    fn table(&self) -> &syn::Path;
+
+    fn returning(&self) -> Option<&Returning>;
+
+    fn accept_expr<'a>(&'a self, visitor: &mut impl Visitor<'a>);
+
+    /// Emits errors for qualified column references outside of an `on conflict` clause.
+    fn check_correlations(&self) {
+        self.accept_expr(&mut CorrelationCheck::new(vec![]));
+    }
+
+    fn to_statement_tokens(&self, tokens: &mut TokenStream);
+}
+
+pub struct Mutation<S> {
+    attrs: Vec<Attribute>,
+    statement: S,
+    alias: Option<Alias>,
+}
+
+impl<S> Parse for Mutation<S>
+where
+    S: Statement,
+{
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let result = Self {
+            attrs: input.call(Attribute::parse_outer)?,
+            statement: input.parse()?,
+            alias: input.call(Alias::parse_optional)?,
+        };
+        result.statement.check_correlations();
+        Ok(result)
+    }
+}
+
+impl<S> ToTokens for Mutation<S>
+where
+    S: Statement,
+{
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let module_name = match &self.alias {
+            Some(alias) => alias.ident(),
+            None => &Ident::new("internal", Span::call_site()),
+        };
+
+        let bind_params = {
+            let mut builder = BindParamsBuilder::new();
+            self.statement.accept_expr(&mut builder);
+            builder.build()
+        };
+        let closure_tokens = self
+            .alias
+            .is_none()
+            .then(|| bind_params.to_closure_token_stream(module_name));
+
+        let row_tokens = match self.statement.returning() {
+            Some(returning) => {
+                let mut tokens = TokenStream::new();
+                returning.to_row_struct_tokens(
+                    &mut tokens,
+                    &self.attrs,
+                    &self.statement.table().to_call_site(1),
+                );
+                tokens
+            }
+            None => {
+                if let Some(attr) = self.attrs.first() {
+                    emit_error!(
+                        attr,
+                        "attributes are only allowed on mutations with a `returning` clause"
+                    );
+                }
+                quote! { pub type Row = (); }
+            }
+        };
+
+        let statement = {
+            let mut tokens = TokenStream::new();
+            self.statement.to_statement_tokens(&mut tokens);
+            tokens
+        };
+
+        let table_path = self.statement.table().to_call_site(2);
+
+        let lifetime = (!bind_params.is_empty()).then_some(quote! { <'a> });
+
+        let module_tokens = quote! {
+            pub mod #module_name {
+                #row_tokens
+
+                #bind_params
+
+                pub struct Mutation #lifetime {
+                    params: Params #lifetime,
+                }
+
+                impl #lifetime Mutation #lifetime {
+                    pub fn new(params: Params #lifetime) -> Self { Self { params } }
+                }
+
+                impl #lifetime ::kosame::mutation::Mutation for Mutation #lifetime {
+                    type Params = Params #lifetime;
+                    type Row = Row;
+
+                    const STATEMENT: ::kosame::mutation::Statement = {
+                        mod scope {
+                            pub(super) use super::params;
+                            pub(super) use #table_path::*;
+                        }
+                        #statement
+                    };
+
+                    fn params(&self) -> &Self::Params {
+                        &self.params
+                    }
+                }
+            }
+        };
+
+        if self.alias.is_some() {
+            module_tokens.to_tokens(tokens);
+        } else {
+            quote! {
+                {
+                    #closure_tokens
+
+                    #module_tokens
+
+                    #module_name::Mutation::new(closure)
+                }
+            }
+            .to_tokens(tokens);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use super::assignment::Set;
+use crate::{expr::Visitor, query::filter::Filter};
+
+mod kw {
+    use syn::custom_keyword;
+
+    custom_keyword!(on);
+    custom_keyword!(conflict);
+
+    custom_keyword!(nothing);
+    custom_keyword!(update);
+}
+
+pub struct OnConflict {
+    _on: kw::on,
+    _conflict: kw::conflict,
+    target: Option<ConflictTarget>,
+    _do: Token![do],
+    action: OnConflictAction,
+}
+
+impl OnConflict {
+    pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        Self::peek(input).then(|| input.parse()).transpose()
+    }
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(kw::on) && input.peek2(kw::conflict)
+    }
+
+    pub fn accept_expr<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        if let OnConflictAction::DoUpdate { set, filter, .. } = &self.action {
+            set.accept_expr(visitor);
+            if let Some(filter) = filter {
+                filter.expr().accept(visitor);
+            }
+        }
+    }
+}
+
+impl Parse for OnConflict {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let result = Self {
+            _on: input.parse()?,
+            _conflict: input.parse()?,
+            target: input
+                .peek(syn::token::Paren)
+                .then(|| input.parse())
+                .transpose()?,
+            _do: input.parse()?,
+            action: input.parse()?,
+        };
+
+        if let OnConflictAction::DoUpdate { _update, .. } = &result.action
+            && result.target.is_none()
+        {
+            emit_error!(
+                _update.span,
+                "`on conflict do update` requires a conflict target, e.g. `on conflict (id)`"
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+impl ToTokens for OnConflict {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let target = self.target.iter().flat_map(|target| target.columns.iter());
+
+        let action = match &self.action {
+            OnConflictAction::DoNothing(_) => {
+                quote! { ::kosame::mutation::OnConflictAction::DoNothing }
+            }
+            OnConflictAction::DoUpdate { set, filter, .. } => {
+                let filter = match filter {
+                    Some(filter) => {
+                        let expr = filter.expr();
+                        quote! { Some(#expr) }
+                    }
+                    None => quote! { None },
+                };
+                quote! {
+                    ::kosame::mutation::OnConflictAction::DoUpdate {
+                        assignments: #set,
+                        filter: #filter,
+                    }
+                }
+            }
+        };
+
+        quote! {
+            ::kosame::mutation::OnConflict::new(
+                &[#(&scope::columns::#target::COLUMN),*],
+                #action,
+            )
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub struct ConflictTarget {
+    _paren: syn::token::Paren,
+    columns: Punctuated<Ident, Token![,]>,
+}
+
+impl Parse for ConflictTarget {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let result = Self {
+            _paren: parenthesized!(content in input),
+            columns: content.parse_terminated(Ident::parse, Token![,])?,
+        };
+
+        if result.columns.is_empty() {
+            return Err(syn::Error::new(
+                result._paren.span.join(),
+                "conflict target must contain at least one column",
+            ));
+        }
+
+        Ok(result)
+    }
+}
+
+#[allow(unused)]
+pub enum OnConflictAction {
+    DoNothing(kw::nothing),
+    DoUpdate {
+        _update: kw::update,
+        set: Set,
+        filter: Option<Filter>,
+    },
+}
+
+impl Parse for OnConflictAction {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::nothing) {
+            Ok(Self::DoNothing(input.parse()?))
+        } else if lookahead.peek(kw::update) {
+            Ok(Self::DoUpdate {
+                _update: input.parse()?,
+                set: input.parse()?,
+                filter: input.call(Filter::parse_optional)?,
+            })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
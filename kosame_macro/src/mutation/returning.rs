@@ -0,0 +1,182 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Attribute, Ident, Path, Token, braced,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::{
+    expr::Visitor,
+    query::{field::QueryField, node_path::QueryNodePath, star::Star},
+    row_struct::RowStruct,
+};
+
+mod kw {
+    use syn::custom_keyword;
+
+    custom_keyword!(returning);
+}
+
+pub struct Returning {
+    _returning: kw::returning,
+    _brace: syn::token::Brace,
+    star: Option<Star>,
+    fields: Punctuated<QueryField, Token![,]>,
+}
+
+impl Returning {
+    pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        Self::peek(input).then(|| input.parse()).transpose()
+    }
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(kw::returning)
+    }
+
+    pub fn accept_expr<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        for field in &self.fields {
+            if let QueryField::Expr { expr, .. } = field {
+                expr.accept(visitor);
+            }
+        }
+    }
+
+    pub fn to_row_struct_tokens(
+        &self,
+        tokens: &mut TokenStream,
+        attrs: &[Attribute],
+        table_path: &Path,
+    ) {
+        let star_field = self.star.as_ref().and_then(|star| {
+            star.alias()
+                .is_some()
+                .then(|| star.to_row_struct_field(table_path))
+        });
+
+        let row_struct = RowStruct::new(
+            attrs.to_vec(),
+            Ident::new("Row", Span::call_site()),
+            star_field
+                .into_iter()
+                .chain(
+                    self.fields
+                        .iter()
+                        .map(|field| field.to_row_struct_field(table_path, &QueryNodePath::new())),
+                )
+                .collect(),
+        );
+
+        if let Some(star) = &self.star
+            && star.alias().is_none()
+        {
+            quote! {
+                #table_path::star! {
+                    (#table_path)
+                    #row_struct
+                }
+            }
+            .to_tokens(tokens);
+        } else {
+            row_struct.to_tokens(tokens);
+        }
+    }
+}
+
+impl Parse for Returning {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let _returning = input.parse()?;
+        let _brace = braced!(content in input);
+
+        let star = if content.fork().parse::<Star>().is_ok() {
+            let star = Some(content.parse()?);
+            if !content.is_empty() {
+                let _: Token![,] = content.parse()?;
+            }
+            star
+        } else {
+            None
+        };
+
+        let fields = content.parse_terminated(QueryField::parse, Token![,])?;
+
+        let mut existing = vec![];
+        for field in &fields {
+            let name = field.name();
+
+            if let QueryField::Relation { .. } = field {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "relations are not allowed in `returning`",
+                ));
+            }
+
+            if field.is_column() && star.is_some() {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "column references are not allowed after `*`",
+                ));
+            }
+
+            let name_string = field
+                .alias()
+                .map(|alias| alias.ident())
+                .unwrap_or(name)
+                .to_string();
+            if existing.contains(&name_string) {
+                return Err(syn::Error::new(
+                    field.span(),
+                    format!("duplicate field `{}`", name_string),
+                ));
+            }
+            existing.push(name_string);
+        }
+
+        Ok(Self {
+            _returning,
+            _brace,
+            star,
+            fields,
+        })
+    }
+}
+
+impl ToTokens for Returning {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let star = self.star.is_some();
+
+        let fields = self.fields.iter().map(|field| match field {
+            QueryField::Column { name, alias, .. } => {
+                let alias = match alias {
+                    Some(alias) => {
+                        let alias = alias.ident().to_string();
+                        quote! { Some(#alias) }
+                    }
+                    None => quote! { None },
+                };
+                quote! {
+                    ::kosame::mutation::ReturningField::Column {
+                        column: &scope::columns::#name::COLUMN,
+                        alias: #alias
+                    }
+                }
+            }
+            QueryField::Expr { expr, alias, .. } => {
+                let alias = alias.ident().to_string();
+                quote! {
+                    ::kosame::mutation::ReturningField::Expr {
+                        expr: #expr,
+                        alias: #alias
+                    }
+                }
+            }
+            QueryField::Relation { .. } => unreachable!("relations are rejected while parsing"),
+        });
+
+        quote! {
+            ::kosame::mutation::Returning::new(#star, &[#(#fields),*])
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -0,0 +1,82 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+
+use super::{Returning, Statement, assignment::Set};
+use crate::{expr::Visitor, path_ext::PathExt, query::filter::Filter};
+
+mod kw {
+    use syn::custom_keyword;
+
+    custom_keyword!(update);
+}
+
+pub struct Update {
+    _update: kw::update,
+    table: syn::Path,
+    set: Set,
+    filter: Option<Filter>,
+    returning: Option<Returning>,
+}
+
+impl Statement for Update {
+    fn table(&self) -> &syn::Path {
+        &self.table
+    }
+
+    fn returning(&self) -> Option<&Returning> {
+        self.returning.as_ref()
+    }
+
+    fn accept_expr<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        self.set.accept_expr(visitor);
+
+        if let Some(filter) = &self.filter {
+            filter.expr().accept(visitor);
+        }
+
+        if let Some(returning) = &self.returning {
+            returning.accept_expr(visitor);
+        }
+    }
+
+    fn to_statement_tokens(&self, tokens: &mut TokenStream) {
+        let table_path = self.table.to_call_site(1);
+        let set = &self.set;
+
+        let filter = match &self.filter {
+            Some(filter) => {
+                let expr = filter.expr();
+                quote! { Some(#expr) }
+            }
+            None => quote! { None },
+        };
+
+        let returning = match &self.returning {
+            Some(returning) => quote! { Some(#returning) },
+            None => quote! { None },
+        };
+
+        quote! {
+            ::kosame::mutation::Statement::Update(::kosame::mutation::Update::new(
+                &#table_path::TABLE,
+                #set,
+                #filter,
+                #returning,
+            ))
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl Parse for Update {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _update: input.parse()?,
+            table: input.parse()?,
+            set: input.parse()?,
+            filter: input.call(Filter::parse_optional)?,
+            returning: input.call(Returning::parse_optional)?,
+        })
+    }
+}
@@ -1,12 +1,12 @@
-mod bind_params;
-mod field;
-mod filter;
+pub(crate) mod bind_params;
+pub(crate) mod field;
+pub(crate) mod filter;
 mod limit;
 mod node;
-mod node_path;
+pub(crate) mod node_path;
 mod offset;
 mod order_by;
-mod star;
+pub(crate) mod star;
 
 use field::QueryField;
 use filter::Filter;
@@ -22,23 +22,37 @@ use syn::{
     parse::{Parse, ParseStream},
 };
 
-use crate::{alias::Alias, path_ext::PathExt, query::bind_params::BindParamsBuilder};
+use crate::{
+    alias::Alias, expr::CorrelationCheck, path_ext::PathExt, query::bind_params::BindParamsBuilder,
+};
 
 pub struct Query {
     attrs: Vec<Attribute>,
     table: syn::Path,
     body: QueryNode,
     alias: Option<Alias>,
+    row: Option<syn::Path>,
+}
+
+impl Query {
+    /// Uses an existing row struct, relative to the query module, instead of generating one.
+    pub fn with_row(mut self, row: syn::Path) -> Self {
+        self.row = Some(row);
+        self
+    }
 }
 
 impl Parse for Query {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self {
+        let result = Self {
             attrs: input.call(Attribute::parse_outer)?,
             table: input.parse()?,
             body: input.parse()?,
             alias: input.call(Alias::parse_optional)?,
-        })
+            row: None,
+        };
+        result.body.accept_expr(&mut CorrelationCheck::new(vec![]));
+        Ok(result)
     }
 }
 
@@ -59,11 +73,14 @@ impl ToTokens for Query {
             .is_none()
             .then(|| bind_params.to_closure_token_stream(module_name));
 
-        let node_tokens = {
-            let mut tokens = proc_macro2::TokenStream::new();
-            self.body
-                .to_row_struct_tokens(&mut tokens, self, &QueryNodePath::new());
-            tokens
+        let node_tokens = match &self.row {
+            Some(row) => quote! { pub use #row as Row; },
+            None => {
+                let mut tokens = proc_macro2::TokenStream::new();
+                self.body
+                    .to_row_struct_tokens(&mut tokens, self, &QueryNodePath::new());
+                tokens
+            }
         };
 
         let query_node = {
@@ -0,0 +1,71 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::Ident;
+
+use super::column_override::ColumnWithOverride;
+use crate::{
+    mutation::{Delete, Mutation},
+    query::Query,
+};
+
+pub struct ByPk<'a> {
+    table_name: &'a Ident,
+    key_columns: Vec<&'a ColumnWithOverride<'a>>,
+}
+
+impl<'a> ByPk<'a> {
+    pub fn new(table_name: &'a Ident, key_columns: Vec<&'a ColumnWithOverride<'a>>) -> Self {
+        Self {
+            table_name,
+            key_columns,
+        }
+    }
+}
+
+impl ToTokens for ByPk<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.key_columns.is_empty() {
+            return;
+        }
+
+        let table_name = self.table_name;
+        let key_names = self
+            .key_columns
+            .iter()
+            .map(|column| column.name_or_alias())
+            .collect::<Vec<_>>();
+
+        // The lookups are generated by the regular query and mutation macros. The table module is
+        // referenced through its parent, because `super::self` is not a valid path.
+        let filter = quote! { #(#key_names = :#key_names)and* };
+
+        let find_by_pk: Query = syn::parse2(quote! {
+            super::#table_name {
+                *,
+                where #filter
+            } as find_by_pk
+        })
+        .expect("generated find_by_pk query should parse");
+        let find_by_pk = find_by_pk.with_row(syn::parse_quote! { super::Select });
+
+        let delete_by_pk: Mutation<Delete> = syn::parse2(quote! {
+            delete from super::#table_name where #filter as delete_by_pk
+        })
+        .expect("generated delete_by_pk mutation should parse");
+
+        quote! {
+            #find_by_pk
+
+            pub fn find_by_pk<'a>(#(#key_names: &'a columns::#key_names::Type),*) -> find_by_pk::Query<'a> {
+                find_by_pk::Query::new(find_by_pk::Params { #(#key_names),* })
+            }
+
+            #delete_by_pk
+
+            pub fn delete_by_pk<'a>(#(#key_names: &'a columns::#key_names::Type),*) -> delete_by_pk::Mutation<'a> {
+                delete_by_pk::Mutation::new(delete_by_pk::Params { #(#key_names),* })
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -1,4 +1,6 @@
-use super::{column_constraint::ColumnConstraints, data_type::DataType};
+use super::{
+    column_constraint::ColumnConstraints, custom_type::CustomTypeUse, data_type::DataType,
+};
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{
@@ -10,6 +12,7 @@ pub struct Column {
     name: Ident,
     data_type: DataType,
     constraints: ColumnConstraints,
+    table_primary_key: bool,
 }
 
 impl Column {
@@ -17,6 +20,56 @@ impl Column {
         &self.name
     }
 
+    pub fn constraints(&self) -> &ColumnConstraints {
+        &self.constraints
+    }
+
+    pub fn is_primary_key(&self) -> bool {
+        self.constraints.primary_key().is_some() || self.table_primary_key
+    }
+
+    /// Marks the column as part of a primary key declared as a table constraint.
+    pub fn set_table_primary_key(&mut self) {
+        self.table_primary_key = true;
+    }
+
+    /// Identity columns are implicitly `not null`, like `serial` columns.
+    pub fn is_identity(&self) -> bool {
+        self.constraints
+            .generated()
+            .is_some_and(|generated| generated.is_identity())
+    }
+
+    /// Columns declared `generated always` cannot be written, so they are left out of the
+    /// generated `Insert` and `Update` structs.
+    pub fn is_generated_always(&self) -> bool {
+        self.constraints
+            .generated()
+            .is_some_and(|generated| generated.is_always())
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.constraints.not_null().is_none() && !self.is_primary_key() && !self.is_identity()
+    }
+
+    pub fn has_default(&self) -> bool {
+        self.constraints.default().is_some() || self.data_type.is_serial() || self.is_identity()
+    }
+
+    /// Marks the data type of the column as user-defined if it was declared with `use name;`.
+    pub fn resolve_custom_type(&mut self, custom_types: &[CustomTypeUse]) {
+        self.data_type.resolve_custom_type(custom_types);
+    }
+
+    /// The user-defined type of this column, which must be in scope of the `table!` call.
+    pub fn custom_type(&self) -> Option<Ident> {
+        self.data_type.custom_type()
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
     pub fn data_type_not_null(&self) -> &DataType {
         &self.data_type
     }
@@ -27,7 +80,7 @@ impl Column {
     }
 
     pub fn data_type_auto(&self) -> TokenStream {
-        if self.constraints.not_null().is_none() && self.constraints.primary_key().is_none() {
+        if self.is_nullable() {
             self.data_type_nullable()
         } else {
             self.data_type_not_null().to_token_stream()
@@ -44,6 +97,7 @@ impl Parse for Column {
             name,
             data_type: r#type,
             constraints: input.parse()?,
+            table_primary_key: false,
         })
     }
 }
@@ -1,11 +1,17 @@
 use std::{fmt::Display, ops::Deref};
 
+use proc_macro2::TokenStream;
+use quote::quote;
 use syn::{
-    Token,
+    Ident, Token, parenthesized,
     parse::{Parse, ParseStream},
+    punctuated::Punctuated,
 };
 
-use crate::expr::Expr;
+use crate::{
+    expr::{CorrelationCheck, Expr},
+    path_ext::PathExt,
+};
 
 pub struct ColumnConstraints(Vec<ColumnConstraint>);
 
@@ -30,6 +36,20 @@ impl ColumnConstraints {
             _ => None,
         })
     }
+
+    pub fn references(&self) -> Option<&References> {
+        self.0.iter().find_map(|c| match c {
+            ColumnConstraint::References(inner) => Some(inner),
+            _ => None,
+        })
+    }
+
+    pub fn generated(&self) -> Option<&Generated> {
+        self.0.iter().find_map(|c| match c {
+            ColumnConstraint::Generated(inner) => Some(inner),
+            _ => None,
+        })
+    }
 }
 
 impl Parse for ColumnConstraints {
@@ -55,6 +75,8 @@ pub enum ColumnConstraint {
     NotNull(NotNull),
     PrimaryKey(PrimaryKey),
     Default(Default),
+    References(References),
+    Generated(Generated),
 }
 
 impl Parse for ColumnConstraint {
@@ -66,6 +88,10 @@ impl Parse for ColumnConstraint {
             Ok(Self::PrimaryKey(input.parse()?))
         } else if lookahead.peek(kw::default) {
             Ok(Self::Default(input.parse()?))
+        } else if lookahead.peek(kw::references) {
+            Ok(Self::References(input.parse()?))
+        } else if lookahead.peek(kw::generated) {
+            Ok(Self::Generated(input.parse()?))
         } else {
             Err(lookahead.error())
         }
@@ -78,6 +104,8 @@ impl Display for ColumnConstraint {
             Self::NotNull(_) => f.write_str("not null")?,
             Self::PrimaryKey(_) => f.write_str("primary key")?,
             Self::Default(_) => f.write_str("default ...")?,
+            Self::References(_) => f.write_str("references ...")?,
+            Self::Generated(_) => f.write_str("generated ...")?,
         };
         Ok(())
     }
@@ -93,6 +121,20 @@ mod kw {
     syn::custom_keyword!(key);
 
     syn::custom_keyword!(references);
+    syn::custom_keyword!(on);
+    syn::custom_keyword!(delete);
+    syn::custom_keyword!(update);
+    syn::custom_keyword!(no);
+    syn::custom_keyword!(action);
+    syn::custom_keyword!(restrict);
+    syn::custom_keyword!(cascade);
+    syn::custom_keyword!(set);
+
+    syn::custom_keyword!(generated);
+    syn::custom_keyword!(always);
+    syn::custom_keyword!(by);
+    syn::custom_keyword!(identity);
+    syn::custom_keyword!(stored);
 }
 
 pub struct NotNull {
@@ -128,11 +170,226 @@ pub struct Default {
     expr: Expr,
 }
 
+impl Default {
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+}
+
 impl Parse for Default {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self {
+        let result = Self {
             _default: input.parse()?,
             expr: input.parse()?,
-        })
+        };
+        result.expr.accept(&mut CorrelationCheck::new(vec![]));
+        Ok(result)
+    }
+}
+
+/// A `generated always as (expr) stored` or `generated always|by default as identity` clause.
+pub enum Generated {
+    Stored { expr: Expr },
+    Identity { always: bool },
+}
+
+impl Generated {
+    pub fn is_identity(&self) -> bool {
+        matches!(self, Self::Identity { .. })
+    }
+
+    /// Whether the database rejects values written to the column.
+    pub fn is_always(&self) -> bool {
+        match self {
+            Self::Stored { .. } => true,
+            Self::Identity { always } => *always,
+        }
+    }
+
+    pub fn expr(&self) -> Option<&Expr> {
+        match self {
+            Self::Stored { expr } => Some(expr),
+            Self::Identity { .. } => None,
+        }
+    }
+}
+
+impl Parse for Generated {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::generated>()?;
+        let lookahead = input.lookahead1();
+        let always = if lookahead.peek(kw::always) {
+            input.parse::<kw::always>()?;
+            true
+        } else if lookahead.peek(kw::by) {
+            input.parse::<kw::by>()?;
+            input.parse::<kw::default>()?;
+            false
+        } else {
+            return Err(lookahead.error());
+        };
+        input.parse::<Token![as]>()?;
+
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::identity) {
+            input.parse::<kw::identity>()?;
+            // Sequence options, such as `start with 100`, only matter to the database.
+            if input.peek(syn::token::Paren) {
+                let content;
+                parenthesized!(content in input);
+                content.parse::<TokenStream>()?;
+            }
+            Ok(Self::Identity { always })
+        } else if lookahead.peek(syn::token::Paren) {
+            let content;
+            let paren = parenthesized!(content in input);
+            let expr: Expr = content.parse()?;
+            expr.accept(&mut CorrelationCheck::new(vec![]));
+            input.parse::<kw::stored>()?;
+            if !always {
+                return Err(syn::Error::new(
+                    paren.span.join(),
+                    "generated columns must be declared `generated always`",
+                ));
+            }
+            Ok(Self::Stored { expr })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+pub struct References {
+    _references: kw::references,
+    table: syn::Path,
+    _paren: syn::token::Paren,
+    columns: Punctuated<Ident, Token![,]>,
+    on_delete: Option<ReferentialAction>,
+    on_update: Option<ReferentialAction>,
+}
+
+impl References {
+    pub fn table(&self) -> &syn::Path {
+        &self.table
+    }
+
+    pub fn columns(&self) -> &Punctuated<Ident, Token![,]> {
+        &self.columns
+    }
+
+    pub fn paren(&self) -> &syn::token::Paren {
+        &self._paren
+    }
+
+    pub fn to_constraint_kind_tokens(&self, columns: &[&Ident]) -> TokenStream {
+        let table = self.table.to_call_site(1);
+        let target_columns = self.columns.iter();
+        let on_delete = ReferentialAction::to_token_stream(self.on_delete.as_ref());
+        let on_update = ReferentialAction::to_token_stream(self.on_update.as_ref());
+        quote! {
+            ::kosame::schema::ConstraintKind::ForeignKey {
+                columns: &[#(&columns::#columns::COLUMN),*],
+                target_schema: #table::SCHEMA,
+                target_table: #table::NAME,
+                target_columns: &[#(&#table::columns::#target_columns::COLUMN),*],
+                on_delete: #on_delete,
+                on_update: #on_update,
+            }
+        }
+    }
+}
+
+impl Parse for References {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let _references = input.parse()?;
+        let table = input.parse()?;
+        if !input.peek(syn::token::Paren) {
+            return Err(input.error(
+                "expected the referenced columns in parentheses, Kosame cannot infer the primary key of the referenced table",
+            ));
+        }
+
+        let mut result = Self {
+            _references,
+            table,
+            _paren: parenthesized!(content in input),
+            columns: content.parse_terminated(Ident::parse, Token![,])?,
+            on_delete: None,
+            on_update: None,
+        };
+
+        while input.peek(kw::on) {
+            input.parse::<kw::on>()?;
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::delete) {
+                let delete = input.parse::<kw::delete>()?;
+                if result.on_delete.is_some() {
+                    return Err(syn::Error::new(delete.span, "duplicate `on delete` clause"));
+                }
+                result.on_delete = Some(input.parse()?);
+            } else if lookahead.peek(kw::update) {
+                let update = input.parse::<kw::update>()?;
+                if result.on_update.is_some() {
+                    return Err(syn::Error::new(update.span, "duplicate `on update` clause"));
+                }
+                result.on_update = Some(input.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
+}
+
+impl ReferentialAction {
+    fn to_token_stream(action: Option<&Self>) -> TokenStream {
+        match action.unwrap_or(&Self::NoAction) {
+            Self::NoAction => quote! { ::kosame::schema::ReferentialAction::NoAction },
+            Self::Restrict => quote! { ::kosame::schema::ReferentialAction::Restrict },
+            Self::Cascade => quote! { ::kosame::schema::ReferentialAction::Cascade },
+            Self::SetNull => quote! { ::kosame::schema::ReferentialAction::SetNull },
+            Self::SetDefault => quote! { ::kosame::schema::ReferentialAction::SetDefault },
+        }
+    }
+}
+
+impl Parse for ReferentialAction {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::no) {
+            input.parse::<kw::no>()?;
+            input.parse::<kw::action>()?;
+            Ok(Self::NoAction)
+        } else if lookahead.peek(kw::restrict) {
+            input.parse::<kw::restrict>()?;
+            Ok(Self::Restrict)
+        } else if lookahead.peek(kw::cascade) {
+            input.parse::<kw::cascade>()?;
+            Ok(Self::Cascade)
+        } else if lookahead.peek(kw::set) {
+            input.parse::<kw::set>()?;
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::null) {
+                input.parse::<kw::null>()?;
+                Ok(Self::SetNull)
+            } else if lookahead.peek(kw::default) {
+                input.parse::<kw::default>()?;
+                Ok(Self::SetDefault)
+            } else {
+                Err(lookahead.error())
+            }
+        } else {
+            Err(lookahead.error())
+        }
     }
 }
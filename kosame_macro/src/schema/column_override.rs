@@ -5,7 +5,11 @@ use syn::{
     parse::{Parse, ParseStream},
 };
 
-use crate::{alias::Alias, schema::column::Column, type_override::TypeOverride};
+use crate::{
+    alias::Alias,
+    schema::{column::Column, column_constraint::Generated},
+    type_override::TypeOverride,
+};
 
 pub struct ColumnOverride {
     name: Ident,
@@ -42,6 +46,10 @@ impl<'a> ColumnWithOverride<'a> {
         }
     }
 
+    pub fn column(&self) -> &Column {
+        self.column
+    }
+
     pub fn name_or_alias(&self) -> &Ident {
         self.alias().unwrap_or(self.column.name())
     }
@@ -57,6 +65,14 @@ impl<'a> ColumnWithOverride<'a> {
             .unwrap_or_else(|| self.column.data_type_auto())
     }
 
+    /// The user-defined type of the column, unless it is replaced by a type override.
+    pub fn custom_type(&self) -> Option<Ident> {
+        self.type_override()
+            .is_none()
+            .then(|| self.column.custom_type())
+            .flatten()
+    }
+
     pub fn type_override(&self) -> Option<&Path> {
         self.column_override.and_then(|column_override| {
             column_override
@@ -80,10 +96,46 @@ impl ToTokens for ColumnWithOverride<'_> {
             None => quote! { None },
         };
         let data_type = self.type_or_override();
+        let custom_type = self
+            .custom_type()
+            .map(|custom_type| quote! { use super::super::super::#custom_type; });
+        let (generated_scope, generated) = match self.column.constraints().generated() {
+            Some(Generated::Stored { expr }) => (
+                Some(quote! { use super::super::__kosame_generated_scope as scope; }),
+                quote! { Some(::kosame::schema::Generated::Stored { expr: #expr }) },
+            ),
+            Some(Generated::Identity { always }) => (
+                None,
+                quote! { Some(::kosame::schema::Generated::Identity { always: #always }) },
+            ),
+            None => (None, quote! { None }),
+        };
+
+        let schema_data_type = self.column.data_type().to_schema_tokens();
+        let nullable = self.column.is_nullable();
+        let primary_key = self.column.is_primary_key();
+        let default = match self.column.constraints().default() {
+            Some(default) => {
+                let expr = default.expr();
+                quote! { Some(#expr) }
+            }
+            None => quote! { None },
+        };
 
         quote! {
             pub mod #name {
-                pub const COLUMN: ::kosame::schema::Column = ::kosame::schema::Column::new(#column_name_string, #alias);
+                #custom_type
+                #generated_scope
+
+                pub const COLUMN: ::kosame::schema::Column = ::kosame::schema::Column::new(
+                    #column_name_string,
+                    #alias,
+                    #schema_data_type,
+                    #nullable,
+                    #primary_key,
+                    #default,
+                    #generated,
+                );
                 pub type Type = #data_type;
             }
         }
@@ -0,0 +1,216 @@
+use convert_case::{Case, Casing};
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use super::{custom_type::CustomTypeUse, data_type::DataType};
+
+mod kw {
+    syn::custom_keyword!(create);
+}
+
+pub struct CompositeType {
+    _create: kw::create,
+    _type: Token![type],
+    name: Ident,
+    _as: Token![as],
+    _paren: syn::token::Paren,
+    attributes: Punctuated<Attribute, Token![,]>,
+    _semi: Token![;],
+}
+
+impl Parse for CompositeType {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let mut result = Self {
+            _create: input.parse()?,
+            _type: input.parse()?,
+            name: input.parse()?,
+            _as: input.parse()?,
+            _paren: parenthesized!(content in input),
+            attributes: content.parse_terminated(Attribute::parse, Token![,])?,
+            _semi: input.parse()?,
+        };
+
+        let mut custom_types = vec![];
+        while CustomTypeUse::peek(input) {
+            custom_types.push(input.parse()?);
+        }
+        for attribute in &mut result.attributes {
+            attribute.data_type.resolve_custom_type(&custom_types);
+        }
+
+        for (index, attribute) in result.attributes.iter().enumerate() {
+            if result
+                .attributes
+                .iter()
+                .take(index)
+                .any(|other| other.name == attribute.name)
+            {
+                emit_error!(
+                    attribute.name.span(),
+                    "duplicate attribute `{}` in composite type `{}`",
+                    attribute.name,
+                    result.name
+                );
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl ToTokens for CompositeType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let name_string = name.to_string();
+        let type_name = format_ident!("{}", name_string.to_case(Case::Pascal), span = name.span());
+
+        let attribute_names = self
+            .attributes
+            .iter()
+            .map(|attribute| &attribute.name)
+            .collect::<Vec<_>>();
+        let attribute_types = self.attributes.iter().map(|attribute| &attribute.data_type);
+
+        let derives = [
+            quote! { Debug },
+            quote! { Default },
+            quote! { Clone },
+            quote! { PartialEq },
+            #[cfg(feature = "serde")]
+            quote! { ::serde::Serialize },
+            #[cfg(feature = "serde-full")]
+            quote! { ::serde::Deserialize },
+        ];
+
+        // Attributes of a composite type cannot be declared `not null`, so every field is optional.
+        quote! {
+            #[derive(#(#derives),*)]
+            pub struct #type_name {
+                #(pub #attribute_names: Option<#attribute_types>,)*
+            }
+
+            pub mod #name {
+                pub type Type = super::#type_name;
+
+                pub const NAME: &str = #name_string;
+            }
+        }
+        .to_tokens(tokens);
+
+        #[cfg(feature = "postgres-types")]
+        {
+            let attribute_name_strings = attribute_names
+                .iter()
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>();
+            let attribute_count = self.attributes.len() as i32;
+            quote! {
+                impl<'a> ::kosame::driver::postgres_types::FromSql<'a> for #type_name {
+                    fn accepts(ty: &::kosame::driver::postgres_types::Type) -> bool {
+                        ty.name() == #name_string
+                    }
+
+                    fn from_sql(
+                        ty: &::kosame::driver::postgres_types::Type,
+                        raw: &'a [u8],
+                    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                        // The attributes are decoded by position, so they must be declared in the
+                        // order of the database. Inside anonymous records, the attribute names are
+                        // unknown and only the count can be checked.
+                        if let ::kosame::driver::postgres_types::Kind::Composite(fields) = ty.kind()
+                            && fields.iter().map(|field| field.name()).ne([#(#attribute_name_strings),*])
+                        {
+                            return Err(format!(
+                                "attributes of composite type `{}` do not match its declaration",
+                                #name_string,
+                            )
+                            .into());
+                        }
+
+                        let attribute_count = ::kosame::driver::postgres_types::int4_from_sql(
+                            raw.get(..4).ok_or("unexpected end of composite value")?,
+                        )?;
+                        if attribute_count != #attribute_count {
+                            return Err(format!(
+                                "expected {} attributes for composite type `{}`, got {}",
+                                #attribute_count,
+                                #name_string,
+                                attribute_count,
+                            )
+                            .into());
+                        }
+
+                        let mut offset = 4;
+
+                        Ok(Self {
+                            #(#attribute_names: ::kosame::driver::postgres_types::record_field_from_sql(raw, &mut offset)?,)*
+                        })
+                    }
+                }
+
+                impl ::kosame::driver::postgres_types::ToSql for #type_name {
+                    fn to_sql(
+                        &self,
+                        ty: &::kosame::driver::postgres_types::Type,
+                        out: &mut ::kosame::driver::postgres_types::BytesMut,
+                    ) -> Result<::kosame::driver::postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                        let ::kosame::driver::postgres_types::Kind::Composite(fields) = ty.kind() else {
+                            return Err(format!("expected composite type `{}`", #name_string).into());
+                        };
+
+                        // The database expects the attributes in its own order, along with their OIDs.
+                        out.extend_from_slice(&(fields.len() as i32).to_be_bytes());
+                        for field in fields {
+                            match field.name() {
+                                #(
+                                    #attribute_name_strings => ::kosame::driver::postgres_types::record_field_to_sql(
+                                        &self.#attribute_names,
+                                        field.type_(),
+                                        out,
+                                    )?,
+                                )*
+                                name => {
+                                    return Err(format!(
+                                        "unknown attribute `{}` in composite type `{}`",
+                                        name,
+                                        #name_string,
+                                    )
+                                    .into());
+                                }
+                            }
+                        }
+                        Ok(::kosame::driver::postgres_types::IsNull::No)
+                    }
+
+                    fn accepts(ty: &::kosame::driver::postgres_types::Type) -> bool {
+                        ty.name() == #name_string
+                    }
+
+                    ::kosame::driver::postgres_types::to_sql_checked!();
+                }
+            }
+            .to_tokens(tokens);
+        }
+    }
+}
+
+struct Attribute {
+    name: Ident,
+    data_type: DataType,
+}
+
+impl Parse for Attribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: input.parse()?,
+            data_type: input.parse()?,
+        })
+    }
+}
@@ -0,0 +1,33 @@
+use syn::{
+    Ident, Token,
+    parse::{Parse, ParseStream},
+};
+
+/// A `use name;` statement following the `create` statement in `kosame::table!` or
+/// `kosame::composite_type!`. It declares that `name` refers to a user-defined type, such as one
+/// declared using `kosame::enum_type!`, which must be in scope of the macro call.
+pub struct CustomTypeUse {
+    _use: Token![use],
+    name: Ident,
+    _semi: Token![;],
+}
+
+impl CustomTypeUse {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(Token![use])
+    }
+
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+}
+
+impl Parse for CustomTypeUse {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _use: input.parse()?,
+            name: input.parse()?,
+            _semi: input.parse()?,
+        })
+    }
+}
@@ -1,51 +1,393 @@
+use std::fmt::Display;
+
 use proc_macro_error::abort;
+use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
-    Ident,
+    Ident, LitInt, Token, bracketed, parenthesized,
     parse::{Parse, ParseStream},
+    punctuated::Punctuated,
 };
 
+use super::custom_type::CustomTypeUse;
+
+const BUILTIN_TYPES: &[&str] = &[
+    "bigint",
+    "bigserial",
+    "bit",
+    "bool",
+    "boolean",
+    "box",
+    "bpchar",
+    "bytea",
+    "char",
+    "character",
+    "cidr",
+    "circle",
+    "citext",
+    "date",
+    "daterange",
+    "decimal",
+    "float",
+    "float4",
+    "float8",
+    "hstore",
+    "inet",
+    "int",
+    "int2",
+    "int4",
+    "int4range",
+    "int8",
+    "int8range",
+    "integer",
+    "interval",
+    "json",
+    "jsonb",
+    "jsonpath",
+    "line",
+    "lseg",
+    "macaddr",
+    "macaddr8",
+    "money",
+    "name",
+    "numeric",
+    "numrange",
+    "oid",
+    "path",
+    "pg_lsn",
+    "point",
+    "polygon",
+    "real",
+    "record",
+    "regclass",
+    "regtype",
+    "serial",
+    "serial2",
+    "serial4",
+    "serial8",
+    "smallint",
+    "smallserial",
+    "text",
+    "time",
+    "timestamp",
+    "timestamptz",
+    "timetz",
+    "tsquery",
+    "tsrange",
+    "tstzrange",
+    "tsvector",
+    "unknown",
+    "uuid",
+    "varbit",
+    "varchar",
+    "xml",
+];
+
+/// The Cargo features that map a built-in type to a Rust type, if there are any.
+fn feature_for_builtin_type(name: &str) -> Option<&'static str> {
+    match name {
+        "numeric" | "decimal" => Some("`rust_decimal`"),
+        "date" | "time" | "time without time zone" => Some("`chrono` or `time`"),
+        _ => None,
+    }
+}
+
 pub struct DataType {
-    name: Ident,
+    name: String,
+    span: Span,
+    params: Vec<u32>,
+    time_zone: Option<bool>,
+    array_dimensions: Vec<Option<u32>>,
+    custom: bool,
 }
 
-impl Parse for DataType {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self {
-            name: input.parse()?,
-        })
+impl DataType {
+    /// The full name of the type without parameters, e.g. `timestamp with time zone`.
+    pub fn name(&self) -> String {
+        match self.time_zone {
+            Some(true) => format!("{} with time zone", self.name),
+            Some(false) => format!("{} without time zone", self.name),
+            None => self.name.clone(),
+        }
     }
-}
 
-impl ToTokens for DataType {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        match self.name.to_string().as_str() {
+    /// Builds the runtime representation of this type, `::kosame::schema::DataType`.
+    pub fn to_schema_tokens(&self) -> TokenStream {
+        let name = &self.name;
+        let params = &self.params;
+        let time_zone = match self.time_zone {
+            Some(time_zone) => quote! { Some(#time_zone) },
+            None => quote! { None },
+        };
+        let array_dimensions = self
+            .array_dimensions
+            .iter()
+            .map(|dimension| match dimension {
+                Some(size) => quote! { Some(#size) },
+                None => quote! { None },
+            });
+        quote! {
+            ::kosame::schema::DataType::new(
+                #name,
+                &[#(#params),*],
+                #time_zone,
+                &[#(#array_dimensions),*],
+            )
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.array_dimensions.is_empty()
+            && matches!(
+                self.name.as_str(),
+                "smallint" | "int2" | "int" | "integer" | "int4" | "bigint" | "int8"
+            )
+    }
+
+    pub fn is_serial(&self) -> bool {
+        matches!(
+            self.name.as_str(),
+            "smallserial" | "serial" | "bigserial" | "serial2" | "serial4" | "serial8"
+        )
+    }
+
+    /// Marks this type as user-defined if it is one of the types declared with `use name;`.
+    pub fn resolve_custom_type(&mut self, custom_types: &[CustomTypeUse]) {
+        self.custom = self.params.is_empty()
+            && self.time_zone.is_none()
+            && !BUILTIN_TYPES.contains(&self.name.as_str())
+            && custom_types
+                .iter()
+                .any(|custom_type| custom_type.name() == &self.name);
+    }
+
+    /// The name of a user-defined type, such as one declared using `kosame::enum_type!`.
+    pub fn custom_type(&self) -> Option<Ident> {
+        self.custom.then(|| Ident::new(&self.name, self.span))
+    }
+
+    fn element_type_tokens(&self) -> proc_macro2::TokenStream {
+        match self.name().as_str() {
             // Built-in / Standard library types
-            "bool" => quote! { bool },
-            "char" => quote! { i8 },
-            "smallint" | "smallserial" => quote! { i16 },
-            "int" | "serial" => quote! { i32 },
+            "bool" | "boolean" => quote! { bool },
+            // Without a length, `char` refers to the single-byte Postgres type.
+            "char" if self.params.is_empty() => quote! { i8 },
+            "smallint" | "int2" | "smallserial" | "serial2" => quote! { i16 },
+            "int" | "integer" | "int4" | "serial" | "serial4" => quote! { i32 },
             "oid" => quote! { u32 },
-            "bigint" | "bigserial" => quote! { i64 },
-            "real" => quote! { f32 },
-            "double precision" => quote! { f64 },
-            "varchar" | "text" | "citext" | "name" | "unknown" => quote! { ::std::string::String },
+            "bigint" | "int8" | "bigserial" | "serial8" => quote! { i64 },
+            "real" | "float4" => quote! { f32 },
+            "float"
+                if self
+                    .params
+                    .first()
+                    .is_some_and(|precision| *precision <= 24) =>
+            {
+                quote! { f32 }
+            }
+            "double precision" | "float8" | "float" => quote! { f64 },
+            "varchar" | "character varying" | "char varying" | "char" | "character" | "bpchar"
+            | "text" | "citext" | "name" | "unknown" => quote! { ::std::string::String },
             "bytea" => quote! { ::std::vec::Vec<u8> },
-            "hstore" => quote! { ::std::collections::HashMap<::std::string::String, ::std::option::Option<::std::string::String>> },
-            "timestamp" | "timestamptz" | "timestamp with time zone" => quote! { ::std::time::SystemTime },
+            "hstore" => {
+                quote! { ::std::collections::HashMap<::std::string::String, ::std::option::Option<::std::string::String>> }
+            }
             "inet" => quote! { ::std::net::IpAddr },
+            "interval" => quote! { ::kosame::types::Interval },
+
+            // Date and time types, preferring `chrono` over `time` if both are enabled
+            #[cfg(feature = "chrono")]
+            "timestamp" | "timestamp without time zone" => {
+                quote! { ::kosame::chrono::NaiveDateTime }
+            }
+            #[cfg(feature = "chrono")]
+            "timestamptz" | "timestamp with time zone" => {
+                quote! { ::kosame::chrono::DateTime<::kosame::chrono::Utc> }
+            }
+            #[cfg(feature = "chrono")]
+            "date" => quote! { ::kosame::chrono::NaiveDate },
+            #[cfg(feature = "chrono")]
+            "time" | "time without time zone" => quote! { ::kosame::chrono::NaiveTime },
+            #[cfg(all(feature = "time", not(feature = "chrono")))]
+            "timestamp" | "timestamp without time zone" => {
+                quote! { ::kosame::time::PrimitiveDateTime }
+            }
+            #[cfg(all(feature = "time", not(feature = "chrono")))]
+            "timestamptz" | "timestamp with time zone" => quote! { ::kosame::time::OffsetDateTime },
+            #[cfg(all(feature = "time", not(feature = "chrono")))]
+            "date" => quote! { ::kosame::time::Date },
+            #[cfg(all(feature = "time", not(feature = "chrono")))]
+            "time" | "time without time zone" => quote! { ::kosame::time::Time },
+            #[cfg(not(any(feature = "chrono", feature = "time")))]
+            "timestamp"
+            | "timestamp without time zone"
+            | "timestamptz"
+            | "timestamp with time zone" => quote! { ::std::time::SystemTime },
 
             // Crates
+            #[cfg(feature = "rust_decimal")]
+            "numeric" | "decimal" => quote! { ::kosame::rust_decimal::Decimal },
+            #[cfg(feature = "uuid")]
+            "uuid" => quote! { ::kosame::uuid::Uuid },
+            #[cfg(not(feature = "uuid"))]
             "uuid" => quote! { ::uuid::Uuid },
+            #[cfg(feature = "serde_json")]
+            "json" | "jsonb" => quote! { ::kosame::serde_json::Value },
+            #[cfg(not(feature = "serde_json"))]
             "json" | "jsonb" => quote! { ::serde_json::Value },
-            _ => {
+            _ if let Some(custom_type) = self.custom_type() => quote! { #custom_type::Type },
+            _ if !BUILTIN_TYPES.contains(&self.name.as_str()) => {
                 abort!(
-                    self.name.span(),
+                    self.span,
                     "cannot determine rust type for unrecognized database type {}, requires type override",
-                    self.name,
+                    self;
+                    help = "if `{}` is declared using a Kosame macro, add `use {};` after the `create` statement", self.name, self.name
                 );
             }
+            name => match feature_for_builtin_type(name) {
+                Some(feature) => abort!(
+                    self.span,
+                    "cannot determine rust type for database type {}, requires type override",
+                    self;
+                    help = "enable the {} feature of the `kosame` crate to map `{}` to a Rust type", feature, name
+                ),
+                None => abort!(
+                    self.span,
+                    "cannot determine rust type for database type {}, requires type override",
+                    self
+                ),
+            },
+        }
+    }
+}
+
+impl Parse for DataType {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let first: Ident = input.parse()?;
+        let span = first.span();
+        let mut name = first.to_string();
+
+        // Multi-word type names are only consumed for the types that have them, so that the
+        // following column constraints are left alone.
+        if name == "double" {
+            input.parse::<kw::precision>()?;
+            name.push_str(" precision");
+        } else if matches!(name.as_str(), "character" | "char" | "bit") && input.peek(kw::varying) {
+            input.parse::<kw::varying>()?;
+            name.push_str(" varying");
+        }
+
+        let params = if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let params = Punctuated::<LitInt, Token![,]>::parse_separated_nonempty(&content)?;
+            if !content.is_empty() {
+                return Err(content.error("expected `,` or `)`"));
+            }
+            params
+                .iter()
+                .map(LitInt::base10_parse)
+                .collect::<syn::Result<_>>()?
+        } else {
+            vec![]
+        };
+
+        let time_zone = if matches!(name.as_str(), "timestamp" | "time") {
+            if input.peek(kw::with) {
+                input.parse::<kw::with>()?;
+                input.parse::<kw::time>()?;
+                input.parse::<kw::zone>()?;
+                Some(true)
+            } else if input.peek(kw::without) {
+                input.parse::<kw::without>()?;
+                input.parse::<kw::time>()?;
+                input.parse::<kw::zone>()?;
+                Some(false)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        let mut array_dimensions = vec![];
+        while input.peek(syn::token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            array_dimensions.push(
+                (!content.is_empty())
+                    .then(|| content.parse::<LitInt>()?.base10_parse())
+                    .transpose()?,
+            );
         }
-        .to_tokens(tokens);
+
+        Ok(Self {
+            name,
+            span,
+            params,
+            time_zone,
+            array_dimensions,
+            custom: false,
+        })
     }
 }
+
+impl Display for DataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)?;
+        if !self.params.is_empty() {
+            f.write_str("(")?;
+            for (index, param) in self.params.iter().enumerate() {
+                write!(f, "{param}")?;
+                if index != self.params.len() - 1 {
+                    f.write_str(", ")?;
+                }
+            }
+            f.write_str(")")?;
+        }
+        match self.time_zone {
+            Some(true) => f.write_str(" with time zone")?,
+            Some(false) => f.write_str(" without time zone")?,
+            None => {}
+        }
+        for dimension in &self.array_dimensions {
+            match dimension {
+                Some(size) => write!(f, "[{size}]")?,
+                None => f.write_str("[]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ToTokens for DataType {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        if self.array_dimensions.is_empty() {
+            self.element_type_tokens().to_tokens(tokens);
+            return;
+        }
+
+        // Array elements can always be null in PostgreSQL, and the driver only decodes
+        // one-dimensional arrays into a `Vec`.
+        if self.array_dimensions.len() > 1 {
+            abort!(
+                self.span,
+                "cannot determine rust type for multi-dimensional array type {}, requires type override",
+                self,
+            );
+        }
+        let element = self.element_type_tokens();
+        quote! { ::std::vec::Vec<::std::option::Option<#element>> }.to_tokens(tokens);
+    }
+}
+
+mod kw {
+    use syn::custom_keyword;
+
+    custom_keyword!(precision);
+    custom_keyword!(varying);
+    custom_keyword!(with);
+    custom_keyword!(without);
+    custom_keyword!(time);
+    custom_keyword!(zone);
+}
@@ -0,0 +1,182 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+};
+
+use super::data_type::DataType;
+use crate::expr::Expr;
+
+mod kw {
+    syn::custom_keyword!(create);
+    syn::custom_keyword!(domain);
+
+    syn::custom_keyword!(constraint);
+    syn::custom_keyword!(not);
+    syn::custom_keyword!(null);
+    syn::custom_keyword!(default);
+    syn::custom_keyword!(check);
+}
+
+pub struct Domain {
+    _create: kw::create,
+    _domain: kw::domain,
+    name: Ident,
+    _as: Token![as],
+    data_type: DataType,
+    _constraints: Vec<DomainConstraint>,
+    _semi: Token![;],
+}
+
+impl Parse for Domain {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _create: input.parse()?,
+            _domain: input.parse()?,
+            name: input.parse()?,
+            _as: input.parse()?,
+            data_type: input.parse()?,
+            _constraints: {
+                let mut constraints = vec![];
+                while !input.peek(Token![;]) {
+                    constraints.push(input.parse()?);
+                }
+                constraints
+            },
+            _semi: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Domain {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let name_string = name.to_string();
+        let type_name = format_ident!("{}", name_string.to_case(Case::Pascal), span = name.span());
+        let data_type = &self.data_type;
+
+        let derives = [
+            quote! { Debug },
+            quote! { Clone },
+            quote! { PartialEq },
+            #[cfg(feature = "serde")]
+            quote! { ::serde::Serialize },
+            #[cfg(feature = "serde-full")]
+            quote! { ::serde::Deserialize },
+        ];
+        let serde_transparent = cfg!(feature = "serde").then(|| quote! { #[serde(transparent)] });
+
+        quote! {
+            #[derive(#(#derives),*)]
+            #serde_transparent
+            pub struct #type_name(pub #data_type);
+
+            impl ::std::ops::Deref for #type_name {
+                type Target = #data_type;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            impl ::std::ops::DerefMut for #type_name {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.0
+                }
+            }
+
+            pub mod #name {
+                pub type Type = super::#type_name;
+
+                pub const NAME: &str = #name_string;
+            }
+        }
+        .to_tokens(tokens);
+
+        // Values of a domain are encoded like values of its base type. Query results report the
+        // base type instead of the domain, so both are accepted.
+        #[cfg(feature = "postgres-types")]
+        quote! {
+            impl<'a> ::kosame::driver::postgres_types::FromSql<'a> for #type_name {
+                fn accepts(ty: &::kosame::driver::postgres_types::Type) -> bool {
+                    ty.name() == #name_string
+                        || <#data_type as ::kosame::driver::postgres_types::FromSql>::accepts(ty)
+                }
+
+                fn from_sql(
+                    ty: &::kosame::driver::postgres_types::Type,
+                    raw: &'a [u8],
+                ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                    let ty = match ty.kind() {
+                        ::kosame::driver::postgres_types::Kind::Domain(base) => base,
+                        _ => ty,
+                    };
+                    <#data_type as ::kosame::driver::postgres_types::FromSql>::from_sql(ty, raw).map(Self)
+                }
+            }
+
+            impl ::kosame::driver::postgres_types::ToSql for #type_name {
+                fn to_sql(
+                    &self,
+                    ty: &::kosame::driver::postgres_types::Type,
+                    out: &mut ::kosame::driver::postgres_types::BytesMut,
+                ) -> Result<::kosame::driver::postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                    let ty = match ty.kind() {
+                        ::kosame::driver::postgres_types::Kind::Domain(base) => base,
+                        _ => ty,
+                    };
+                    self.0.to_sql(ty, out)
+                }
+
+                fn accepts(ty: &::kosame::driver::postgres_types::Type) -> bool {
+                    ty.name() == #name_string
+                        || <#data_type as ::kosame::driver::postgres_types::ToSql>::accepts(ty)
+                }
+
+                ::kosame::driver::postgres_types::to_sql_checked!();
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+#[allow(unused)]
+enum DomainConstraint {
+    NotNull(kw::not, kw::null),
+    Null(kw::null),
+    Default(kw::default, Expr),
+    Check {
+        name: Option<(kw::constraint, Ident)>,
+        _check: kw::check,
+        _paren: syn::token::Paren,
+        expr: Expr,
+    },
+}
+
+impl Parse for DomainConstraint {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::not) {
+            Ok(Self::NotNull(input.parse()?, input.parse()?))
+        } else if lookahead.peek(kw::null) {
+            Ok(Self::Null(input.parse()?))
+        } else if lookahead.peek(kw::default) {
+            Ok(Self::Default(input.parse()?, input.parse()?))
+        } else if lookahead.peek(kw::constraint) || lookahead.peek(kw::check) {
+            let content;
+            Ok(Self::Check {
+                name: input
+                    .peek(kw::constraint)
+                    .then(|| Ok::<_, syn::Error>((input.parse()?, input.parse()?)))
+                    .transpose()?,
+                _check: input.parse()?,
+                _paren: parenthesized!(content in input),
+                expr: content.parse()?,
+            })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use convert_case::{Case, Casing};
+use proc_macro_error::{abort, emit_error};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    Ident, LitStr, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+mod kw {
+    syn::custom_keyword!(create);
+}
+
+pub struct EnumType {
+    _create: kw::create,
+    _type: Token![type],
+    name: Ident,
+    _as: Token![as],
+    _enum: Token![enum],
+    _paren: syn::token::Paren,
+    variants: Punctuated<Variant, Token![,]>,
+    _semi: Token![;],
+}
+
+impl Parse for EnumType {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let result = Self {
+            _create: input.parse()?,
+            _type: input.parse()?,
+            name: input.parse()?,
+            _as: input.parse()?,
+            _enum: input.parse()?,
+            _paren: parenthesized!(content in input),
+            variants: content.parse_terminated(Variant::parse, Token![,])?,
+            _semi: input.parse()?,
+        };
+
+        for (index, variant) in result.variants.iter().enumerate() {
+            let label = variant.label.value();
+            if result
+                .variants
+                .iter()
+                .take(index)
+                .any(|other| other.label.value() == label)
+            {
+                emit_error!(
+                    variant.label.span(),
+                    "duplicate label \"{}\" in enum type `{}`",
+                    label,
+                    result.name
+                );
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl ToTokens for EnumType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let name_string = name.to_string();
+        let type_name = format_ident!("{}", name_string.to_case(Case::Pascal), span = name.span());
+
+        let labels = self
+            .variants
+            .iter()
+            .map(|variant| &variant.label)
+            .collect::<Vec<_>>();
+        let variant_names = self.variants.iter().map(Variant::name).collect::<Vec<_>>();
+
+        let derives = [
+            quote! { Debug },
+            quote! { Clone },
+            quote! { Copy },
+            quote! { PartialEq },
+            quote! { Eq },
+            quote! { Hash },
+            #[cfg(feature = "serde")]
+            quote! { ::serde::Serialize },
+            #[cfg(feature = "serde-full")]
+            quote! { ::serde::Deserialize },
+        ];
+
+        let variant_attrs = labels.iter().map(|label| {
+            if cfg!(feature = "serde") {
+                quote! { #[serde(rename = #label)] }
+            } else {
+                quote! {}
+            }
+        });
+
+        quote! {
+            #[derive(#(#derives),*)]
+            pub enum #type_name {
+                #(
+                    #variant_attrs
+                    #variant_names,
+                )*
+            }
+
+            impl #type_name {
+                pub const fn as_str(&self) -> &'static str {
+                    match self {
+                        #(Self::#variant_names => #labels,)*
+                    }
+                }
+            }
+
+            pub mod #name {
+                pub type Type = super::#type_name;
+
+                pub const NAME: &str = #name_string;
+                pub const LABELS: &[&str] = &[#(#labels),*];
+            }
+        }
+        .to_tokens(tokens);
+
+        #[cfg(feature = "postgres-types")]
+        {
+            let error = format!("unknown label for enum type `{name_string}`");
+            quote! {
+                impl<'a> ::kosame::driver::postgres_types::FromSql<'a> for #type_name {
+                    fn accepts(ty: &::kosame::driver::postgres_types::Type) -> bool {
+                        ty.name() == #name_string
+                    }
+
+                    fn from_sql(
+                        _ty: &::kosame::driver::postgres_types::Type,
+                        raw: &'a [u8],
+                    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                        match ::std::str::from_utf8(raw)? {
+                            #(#labels => Ok(Self::#variant_names),)*
+                            _ => Err(#error.into()),
+                        }
+                    }
+                }
+
+                impl ::kosame::driver::postgres_types::ToSql for #type_name {
+                    fn to_sql(
+                        &self,
+                        _ty: &::kosame::driver::postgres_types::Type,
+                        out: &mut ::kosame::driver::postgres_types::BytesMut,
+                    ) -> Result<::kosame::driver::postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                        out.extend_from_slice(self.as_str().as_bytes());
+                        Ok(::kosame::driver::postgres_types::IsNull::No)
+                    }
+
+                    fn accepts(ty: &::kosame::driver::postgres_types::Type) -> bool {
+                        ty.name() == #name_string
+                    }
+
+                    ::kosame::driver::postgres_types::to_sql_checked!();
+                }
+            }
+            .to_tokens(tokens);
+        }
+    }
+}
+
+/// A label of an enum type, optionally followed by the name of the Rust variant, e.g.
+/// `"in progress" as Started`.
+struct Variant {
+    label: LitStr,
+    alias: Option<(Token![as], Ident)>,
+}
+
+impl Variant {
+    fn name(&self) -> Ident {
+        if let Some((_, alias)) = &self.alias {
+            return alias.clone();
+        }
+        let name = self.label.value().to_case(Case::Pascal);
+        match syn::parse_str::<Ident>(&name) {
+            Ok(ident) => Ident::new(&ident.to_string(), self.label.span()),
+            Err(_) => abort!(
+                self.label.span(),
+                "label \"{}\" is not a valid Rust identifier, specify a variant name using `as`",
+                self.label.value()
+            ),
+        }
+    }
+}
+
+impl Parse for Variant {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            label: input.parse()?,
+            alias: input
+                .peek(Token![as])
+                .then(|| Ok::<_, syn::Error>((input.parse()?, input.parse()?)))
+                .transpose()?,
+        })
+    }
+}
@@ -1,4 +1,7 @@
-use syn::parse::{Parse, ParseStream};
+use syn::{
+    Token,
+    parse::{Parse, ParseStream},
+};
 
 use super::{column_override::ColumnOverride, relation::Relation};
 
@@ -9,7 +12,7 @@ pub enum FieldSpec {
 
 impl Parse for FieldSpec {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        if input.peek3(syn::token::Paren) {
+        if input.peek3(syn::token::Paren) || input.peek3(Token![<=]) {
             Ok(Self::Relation(input.parse()?))
         } else {
             Ok(Self::ColumnOverride(input.parse()?))
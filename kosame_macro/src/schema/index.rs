@@ -0,0 +1,169 @@
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+use super::column_override::ColumnWithOverride;
+use crate::{
+    expr::{CorrelationCheck, Expr},
+    query::bind_params::BindParamsBuilder,
+};
+
+mod kw {
+    syn::custom_keyword!(create);
+    syn::custom_keyword!(unique);
+    syn::custom_keyword!(index);
+    syn::custom_keyword!(on);
+    syn::custom_keyword!(using);
+}
+
+/// A `create [unique] index [name] on table [using method] (columns) [where predicate];` statement
+/// following the `create table` statement in `kosame::table!`.
+pub struct Index {
+    _create: kw::create,
+    unique: Option<kw::unique>,
+    _index: kw::index,
+    name: Option<Ident>,
+    _on: kw::on,
+    table_schema: Option<(Ident, Token![.])>,
+    table: Ident,
+    method: Option<(kw::using, Ident)>,
+    _paren: syn::token::Paren,
+    columns: Punctuated<Ident, Token![,]>,
+    predicate: Option<(Token![where], Expr)>,
+    _semi: Token![;],
+}
+
+impl Index {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(kw::create) && (input.peek2(kw::index) || input.peek2(kw::unique))
+    }
+
+    pub fn table_schema(&self) -> Option<&Ident> {
+        self.table_schema.as_ref().map(|(schema, _)| schema)
+    }
+
+    pub fn table(&self) -> &Ident {
+        &self.table
+    }
+
+    pub fn to_token_stream(&self, table_columns: &[ColumnWithOverride]) -> TokenStream {
+        let name = match &self.name {
+            Some(name) => {
+                let name = name.to_string();
+                quote! { Some(#name) }
+            }
+            None => quote! { None },
+        };
+        let unique = self.unique.is_some();
+        let columns = self
+            .columns
+            .iter()
+            .filter_map(|column| {
+                let resolved = table_columns
+                    .iter()
+                    .find(|table_column| table_column.column().name() == column)
+                    .map(ColumnWithOverride::name_or_alias);
+                if resolved.is_none() {
+                    emit_error!(
+                        column.span(),
+                        "index column `{}` does not match any column name",
+                        column
+                    );
+                }
+                resolved
+            })
+            .collect::<Vec<_>>();
+        let method = match &self.method {
+            Some((_, method)) => {
+                let method = method.to_string();
+                quote! { Some(#method) }
+            }
+            None => quote! { None },
+        };
+        let predicate = match &self.predicate {
+            Some((_, expr)) => {
+                // Like check constraints, the predicate refers to columns by their database names.
+                let column_names = table_columns.iter().map(|column| column.column().name());
+                let aliases = table_columns.iter().map(ColumnWithOverride::name_or_alias);
+                quote! {
+                    Some({
+                        mod scope {
+                            pub(super) mod columns {
+                                #(pub(in super::super) use super::super::columns::#aliases as #column_names;)*
+                            }
+                        }
+                        #expr
+                    })
+                }
+            }
+            None => quote! { None },
+        };
+
+        quote! {
+            ::kosame::schema::Index::new(
+                #name,
+                #unique,
+                &[#(&columns::#columns::COLUMN),*],
+                #method,
+                #predicate,
+            )
+        }
+    }
+}
+
+impl Parse for Index {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let result = Self {
+            _create: input.parse()?,
+            unique: input.parse()?,
+            _index: input.parse()?,
+            name: (!input.peek(kw::on)).then(|| input.parse()).transpose()?,
+            _on: input.parse()?,
+            table_schema: input
+                .peek2(Token![.])
+                .then(|| Ok::<_, syn::Error>((input.parse()?, input.parse()?)))
+                .transpose()?,
+            table: input.parse()?,
+            method: input
+                .peek(kw::using)
+                .then(|| Ok::<_, syn::Error>((input.parse()?, input.parse()?)))
+                .transpose()?,
+            _paren: parenthesized!(content in input),
+            columns: content.parse_terminated(Ident::parse, Token![,])?,
+            predicate: input
+                .peek(Token![where])
+                .then(|| Ok::<_, syn::Error>((input.parse()?, input.parse()?)))
+                .transpose()?,
+            _semi: input.parse()?,
+        };
+
+        if result.columns.is_empty() {
+            emit_error!(
+                result._paren.span.span(),
+                "at least one column must be specified for index"
+            );
+        }
+
+        if let Some((_, expr)) = &result.predicate {
+            expr.accept(&mut CorrelationCheck::new(vec![]));
+
+            let mut builder = BindParamsBuilder::new();
+            expr.accept(&mut builder);
+            if !builder.build().is_empty() {
+                emit_error!(
+                    result._paren.span.span(),
+                    "bind parameters are not allowed in index predicates"
+                );
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -1,7 +1,16 @@
+pub(crate) mod by_pk;
 pub(crate) mod column;
 pub(crate) mod column_constraint;
 pub(crate) mod column_override;
+pub(crate) mod composite_type;
+pub(crate) mod custom_type;
 pub(crate) mod data_type;
+pub(crate) mod domain;
+pub(crate) mod enum_type;
 pub(crate) mod field_spec;
+pub(crate) mod index;
+pub(crate) mod record;
+pub(crate) mod registry;
 pub(crate) mod relation;
 pub(crate) mod table;
+pub(crate) mod table_constraint;
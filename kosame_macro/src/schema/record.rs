@@ -0,0 +1,205 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+
+use super::column_override::ColumnWithOverride;
+
+fn derives() -> Vec<TokenStream> {
+    vec![
+        quote! { Debug },
+        #[cfg(feature = "serde")]
+        quote! { ::serde::Serialize },
+        #[cfg(feature = "serde-full")]
+        quote! { ::serde::Deserialize },
+    ]
+}
+
+pub struct InsertStruct<'a> {
+    columns: &'a [ColumnWithOverride<'a>],
+}
+
+impl<'a> InsertStruct<'a> {
+    pub fn new(columns: &'a [ColumnWithOverride<'a>]) -> Self {
+        Self { columns }
+    }
+}
+
+impl ToTokens for InsertStruct<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let derives = derives();
+
+        let mut fields = vec![];
+        let mut push_columns = vec![];
+        let mut push_params = vec![];
+        for column in self
+            .columns
+            .iter()
+            .filter(|column| !column.column().is_generated_always())
+        {
+            let name = column.name_or_alias();
+
+            // Columns with a default value or a nullable type can be left out of the insert.
+            if column.column().has_default() || column.column().is_nullable() {
+                let r#type = match column.type_override() {
+                    Some(type_override) => type_override.to_token_stream(),
+                    None => column.column().data_type_not_null().to_token_stream(),
+                };
+                fields.push(quote! { pub #name: Option<#r#type> });
+                push_columns.push(quote! {
+                    if self.#name.is_some() {
+                        result.push(&columns::#name::COLUMN);
+                    }
+                });
+                push_params.push(quote! {
+                    if let Some(value) = &this.#name {
+                        params.push(value);
+                    }
+                });
+            } else {
+                let r#type = column.type_or_override();
+                fields.push(quote! { pub #name: #r#type });
+                push_columns.push(quote! { result.push(&columns::#name::COLUMN); });
+                push_params.push(quote! { params.push(&this.#name); });
+            }
+        }
+
+        quote! {
+            #[derive(#(#derives),*)]
+            pub struct Insert {
+                #(#fields,)*
+            }
+
+            impl ::kosame::mutation::InsertRecord for Insert {
+                const TABLE: &'static ::kosame::schema::Table = &TABLE;
+
+                fn columns(&self) -> Vec<&'static ::kosame::schema::Column> {
+                    let mut result = vec![];
+                    #(#push_columns)*
+                    result
+                }
+            }
+        }
+        .to_tokens(tokens);
+
+        #[cfg(feature = "postgres-types")]
+        quote! {
+            impl<'a> ::kosame::params::Params<Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)>> for &'a Insert {
+                fn to_driver(&self) -> Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> {
+                    let this: &'a Insert = self;
+                    let mut params: Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> = vec![];
+                    #(#push_params)*
+                    params
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+pub struct UpdateStruct<'a> {
+    columns: &'a [ColumnWithOverride<'a>],
+    key_columns: Vec<&'a ColumnWithOverride<'a>>,
+}
+
+impl<'a> UpdateStruct<'a> {
+    pub fn new(
+        columns: &'a [ColumnWithOverride<'a>],
+        key_columns: Vec<&'a ColumnWithOverride<'a>>,
+    ) -> Self {
+        Self {
+            columns,
+            key_columns,
+        }
+    }
+}
+
+impl ToTokens for UpdateStruct<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // Without a primary key there is no way to address the row to update.
+        if self.key_columns.is_empty() {
+            return;
+        }
+
+        let derives = derives();
+
+        let columns = self
+            .columns
+            .iter()
+            .filter(|column| !column.column().is_generated_always())
+            .collect::<Vec<_>>();
+        let names = columns
+            .iter()
+            .map(|column| column.name_or_alias())
+            .collect::<Vec<_>>();
+        let types = columns.iter().map(|column| column.type_or_override());
+
+        let key_names = self
+            .key_columns
+            .iter()
+            .map(|column| column.name_or_alias())
+            .collect::<Vec<_>>();
+        let key_type = match key_names.as_slice() {
+            [name] => quote! { columns::#name::Type },
+            _ => quote! { (#(columns::#key_names::Type,)*) },
+        };
+
+        quote! {
+            #[derive(Default, #(#derives),*)]
+            pub struct Update {
+                #(pub #names: Option<#types>,)*
+            }
+
+            pub struct UpdateParams<'a>(&'a Update, &'a #key_type);
+
+            impl ::kosame::mutation::UpdateRecord for Update {
+                type Key = #key_type;
+                type Params<'a> = UpdateParams<'a>;
+
+                const TABLE: &'static ::kosame::schema::Table = &TABLE;
+                const KEY_COLUMNS: &'static [&'static ::kosame::schema::Column] =
+                    &[#(&columns::#key_names::COLUMN),*];
+
+                fn columns(&self) -> Vec<&'static ::kosame::schema::Column> {
+                    let mut result = vec![];
+                    #(
+                        if self.#names.is_some() {
+                            result.push(&columns::#names::COLUMN);
+                        }
+                    )*
+                    result
+                }
+
+                fn params<'a>(&'a self, key: &'a Self::Key) -> Self::Params<'a> {
+                    UpdateParams(self, key)
+                }
+            }
+        }
+        .to_tokens(tokens);
+
+        #[cfg(feature = "postgres-types")]
+        {
+            let key_params = match key_names.as_slice() {
+                [_] => vec![quote! { params.push(key); }],
+                _ => (0..key_names.len())
+                    .map(syn::Index::from)
+                    .map(|index| quote! { params.push(&key.#index); })
+                    .collect(),
+            };
+            quote! {
+                impl<'a> ::kosame::params::Params<Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)>> for UpdateParams<'a> {
+                    fn to_driver(&self) -> Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> {
+                        let UpdateParams(this, key) = *self;
+                        let mut params: Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> = vec![];
+                        #(
+                            if let Some(value) = &this.#names {
+                                params.push(value);
+                            }
+                        )*
+                        #(#key_params)*
+                        params
+                    }
+                }
+            }
+            .to_tokens(tokens);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Path, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+/// The paths of all modules generated by `kosame::table!` that make up a database schema, e.g.
+/// `kosame::schema!(posts, comments, analytics::page_views)`.
+pub struct Schema {
+    tables: Punctuated<Path, Token![,]>,
+}
+
+impl Parse for Schema {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let result = Self {
+            tables: input.parse_terminated(Path::parse, Token![,])?,
+        };
+
+        let tables = result
+            .tables
+            .iter()
+            .map(|table| table.to_token_stream().to_string())
+            .collect::<Vec<_>>();
+        for (index, table) in result.tables.iter().enumerate() {
+            if tables[..index].contains(&tables[index]) {
+                emit_error!(
+                    table.span(),
+                    "duplicate table `{}` in schema",
+                    tables[index]
+                );
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl ToTokens for Schema {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let tables = self.tables.iter();
+        quote! {
+            ::kosame::schema::Schema::new(&[#(&#tables::TABLE),*])
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -18,12 +18,13 @@ use crate::{
 pub struct Relation {
     name: Ident,
     _colon: Token![:],
-    _source_paren: syn::token::Paren,
+    _source_paren: Option<syn::token::Paren>,
     source_columns: Punctuated<Ident, Token![,]>,
     arrow: Arrow,
     target_table: syn::Path,
-    _target_paren: syn::token::Paren,
+    _target_paren: Option<syn::token::Paren>,
     target_columns: Punctuated<Ident, Token![,]>,
+    junction: Option<Box<Junction>>,
 }
 
 impl Relation {
@@ -31,15 +32,80 @@ impl Relation {
         &self.name
     }
 
-    pub fn to_token_stream(&self) -> TokenStream {
+    pub fn to_token_stream(&self, table_name: &Ident) -> TokenStream {
         let name = &self.name;
         let name_string = name.to_string();
 
         let target = &self.target_table;
         let target_path = target.to_call_site(3);
 
+        if self._source_paren.is_none() {
+            // The columns of a name-only relation are taken from the foreign key of the target
+            // table that references this table.
+            return quote! {
+                pub mod #name {
+                    pub use #target_path as target_table;
+
+                    pub use target_table::foreign_keys::#table_name::target_columns as source_columns;
+                    pub use target_table::foreign_keys::#table_name::source_columns as target_columns;
+
+                    pub const RELATION: ::kosame::schema::Relation = ::kosame::schema::Relation::new(
+                        #name_string,
+                        super::super::NAME,
+                        target_table::foreign_keys::#table_name::RELATION.target_columns(),
+                        target_table::NAME,
+                        target_table::foreign_keys::#table_name::RELATION.source_columns(),
+                        None,
+                    );
+
+                    pub type Type<T> = ::kosame::relation::OneToMany<T>;
+                }
+            };
+        }
+
         let source_columns = self.source_columns.iter();
         let source_columns2 = source_columns.clone();
+
+        if let Some(junction) = &self.junction {
+            // The target table of a many-to-many relation is the one behind the junction table.
+            let junction_path = target_path;
+            let target_path = junction.target_table.to_call_site(3);
+            let junction_source_columns = self.target_columns.iter();
+            let junction_target_columns = junction.junction_columns.iter();
+            let target_columns = junction.target_columns.iter();
+            let target_columns2 = target_columns.clone();
+
+            return quote! {
+                pub mod #name {
+                    pub use #target_path as target_table;
+                    pub use #junction_path as junction_table;
+
+                    pub mod source_columns {
+                        #(pub use super::super::super::columns::#source_columns;)*
+                    }
+
+                    pub mod target_columns {
+                        #(pub use super::target_table::columns::#target_columns;)*
+                    }
+
+                    pub const RELATION: ::kosame::schema::Relation = ::kosame::schema::Relation::new(
+                        #name_string,
+                        super::super::NAME,
+                        &[#(&source_columns::#source_columns2::COLUMN),*],
+                        target_table::NAME,
+                        &[#(&target_columns::#target_columns2::COLUMN),*],
+                        Some(::kosame::schema::Junction::new(
+                            &junction_table::TABLE,
+                            &[#(&junction_table::columns::#junction_source_columns::COLUMN),*],
+                            &[#(&junction_table::columns::#junction_target_columns::COLUMN),*],
+                        )),
+                    );
+
+                    pub type Type<T> = ::kosame::relation::OneToMany<T>;
+                }
+            };
+        }
+
         let target_columns = self.target_columns.iter();
         let target_columns2 = target_columns.clone();
 
@@ -69,6 +135,7 @@ impl Relation {
                     &[#(&source_columns::#source_columns2::COLUMN),*],
                     target_table::NAME,
                     &[#(&target_columns::#target_columns2::COLUMN),*],
+                    None,
                 );
 
                 pub type Type<T> = #relation_type;
@@ -79,29 +146,79 @@ impl Relation {
 
 impl Parse for Relation {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        let _colon = input.parse()?;
+
+        // `name: <= table` declares the inverse of a foreign key without restating its columns.
+        if input.peek(Token![<=]) {
+            return Ok(Self {
+                name,
+                _colon,
+                _source_paren: None,
+                source_columns: Punctuated::new(),
+                arrow: input.parse()?,
+                target_table: input.parse()?,
+                _target_paren: None,
+                target_columns: Punctuated::new(),
+                junction: None,
+            });
+        }
+
         let source_content;
         let dest_content;
         let result = Self {
-            name: input.parse()?,
-            _colon: input.parse()?,
-            _source_paren: parenthesized!(source_content in input),
+            name,
+            _colon,
+            _source_paren: Some(parenthesized!(source_content in input)),
             source_columns: source_content.parse_terminated(Ident::parse, Token![,])?,
             arrow: input.parse()?,
             target_table: input.parse()?,
-            _target_paren: parenthesized!(dest_content in input),
+            _target_paren: Some(parenthesized!(dest_content in input)),
             target_columns: dest_content.parse_terminated(Ident::parse, Token![,])?,
+            junction: input
+                .peek(syn::token::Paren)
+                .then(|| input.parse())
+                .transpose()?,
         };
 
-        if result.source_columns.is_empty() {
+        if let Some(junction) = &result.junction {
+            if let Arrow::ManyToOne(arrow) = &result.arrow {
+                emit_error!(
+                    arrow.span(),
+                    "the junction table of relation `{}` must point to this table with `<=`",
+                    result.name
+                );
+            }
+            if junction.junction_columns.is_empty() {
+                emit_error!(
+                    junction._paren.span.span(),
+                    "at least one column must be specified for relation `{}`",
+                    result.name
+                );
+            }
+            if junction.junction_columns.len() != junction.target_columns.len() {
+                emit_error!(
+                    junction._target_paren.span.span(),
+                    "number of columns must match on both side of the relation `{}`",
+                    result.name
+                );
+            }
+        }
+
+        if let Some(paren) = &result._source_paren
+            && result.source_columns.is_empty()
+        {
             emit_error!(
-                result._source_paren.span.span(),
+                paren.span.span(),
                 "at least one column must be specified for relation `{}`",
                 result.name
             );
         }
-        if result.source_columns.len() != result.target_columns.len() {
+        if let Some(paren) = &result._target_paren
+            && result.source_columns.len() != result.target_columns.len()
+        {
             emit_error!(
-                result._target_paren.span.span(),
+                paren.span.span(),
                 "number of columns must match on both side of the relation `{}`",
                 result.name
             );
@@ -111,6 +228,31 @@ impl Parse for Relation {
     }
 }
 
+/// The second hop of a many-to-many relation, from the junction table to the target table.
+struct Junction {
+    _paren: syn::token::Paren,
+    junction_columns: Punctuated<Ident, Token![,]>,
+    _arrow: Token![=>],
+    target_table: syn::Path,
+    _target_paren: syn::token::Paren,
+    target_columns: Punctuated<Ident, Token![,]>,
+}
+
+impl Parse for Junction {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let target_content;
+        Ok(Self {
+            _paren: parenthesized!(content in input),
+            junction_columns: content.parse_terminated(Ident::parse, Token![,])?,
+            _arrow: input.parse()?,
+            target_table: input.parse()?,
+            _target_paren: parenthesized!(target_content in input),
+            target_columns: target_content.parse_terminated(Ident::parse, Token![,])?,
+        })
+    }
+}
+
 #[allow(unused)]
 enum Arrow {
     ManyToOne(Token![=>]),
@@ -146,6 +288,10 @@ impl Docs for Relation {
 impl Display for Relation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         Display::fmt(&self.name, f)?;
+        if self._source_paren.is_none() {
+            f.write_str(": <= ")?;
+            return f.write_str(&self.target_table.to_token_stream().to_string());
+        }
         f.write_str(": (")?;
         f.write_str(
             &self
@@ -172,6 +318,29 @@ impl Display for Relation {
                 .join(", "),
         )?;
         f.write_str(")")?;
+        if let Some(junction) = &self.junction {
+            f.write_str(" (")?;
+            f.write_str(
+                &junction
+                    .junction_columns
+                    .iter()
+                    .map(|column| column.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )?;
+            f.write_str(") => ")?;
+            f.write_str(&junction.target_table.to_token_stream().to_string())?;
+            f.write_str(" (")?;
+            f.write_str(
+                &junction
+                    .target_columns
+                    .iter()
+                    .map(|column| column.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )?;
+            f.write_str(")")?;
+        }
         Ok(())
     }
 }
@@ -1,6 +1,16 @@
 use std::sync::atomic::Ordering;
 
-use super::{column::Column, field_spec::FieldSpec, relation::Relation};
+use super::{
+    by_pk::ByPk,
+    column::Column,
+    column_constraint::References,
+    custom_type::CustomTypeUse,
+    field_spec::FieldSpec,
+    index::Index,
+    record::{InsertStruct, UpdateStruct},
+    relation::Relation,
+    table_constraint::TableConstraint,
+};
 use crate::{
     row_struct::{RowStruct, RowStructField},
     schema::column_override::{ColumnOverride, ColumnWithOverride},
@@ -8,6 +18,7 @@ use crate::{
 use proc_macro_error::emit_error;
 use proc_macro2::Span;
 use quote::{ToTokens, quote};
+use syn::spanned::Spanned;
 use syn::{
     Ident, Token,
     parse::{Parse, ParseStream},
@@ -17,19 +28,65 @@ use syn::{
 mod kw {
     syn::custom_keyword!(create);
     syn::custom_keyword!(table);
+    syn::custom_keyword!(view);
+    syn::custom_keyword!(materialized);
+}
+
+#[allow(unused)]
+enum TableKind {
+    Table(kw::table),
+    View(kw::view),
+    MaterializedView(kw::materialized, kw::view),
+}
+
+impl TableKind {
+    fn is_view(&self) -> bool {
+        !matches!(self, Self::Table(_))
+    }
+}
+
+impl Parse for TableKind {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::table) {
+            Ok(Self::Table(input.parse()?))
+        } else if lookahead.peek(kw::view) {
+            Ok(Self::View(input.parse()?))
+        } else if lookahead.peek(kw::materialized) {
+            Ok(Self::MaterializedView(input.parse()?, input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for TableKind {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Table(_) => quote! { ::kosame::schema::TableKind::Table },
+            Self::View(_) => quote! { ::kosame::schema::TableKind::View },
+            Self::MaterializedView(..) => quote! { ::kosame::schema::TableKind::MaterializedView },
+        }
+        .to_tokens(tokens);
+    }
 }
 
 pub struct Table {
     _create: kw::create,
-    _table: kw::table,
+    kind: TableKind,
     _paren: syn::token::Paren,
 
+    schema: Option<(Ident, Token![.])>,
     name: Ident,
-    columns: Punctuated<Column, Token![,]>,
+    columns: Vec<Column>,
+    constraints: Vec<TableConstraint>,
 
     _semi: Token![;],
+    indexes: Vec<Index>,
 
     field_specs: Punctuated<FieldSpec, Token![,]>,
+
+    foreign_key_relations: Vec<Relation>,
 }
 
 impl Table {
@@ -52,6 +109,11 @@ impl Table {
     }
 
     fn relations(&self) -> impl Iterator<Item = &Relation> {
+        self.declared_relations()
+            .chain(self.foreign_key_relations.iter())
+    }
+
+    fn declared_relations(&self) -> impl Iterator<Item = &Relation> {
         self.field_specs
             .iter()
             .filter_map(|field_spec| match field_spec {
@@ -60,6 +122,74 @@ impl Table {
             })
     }
 
+    fn foreign_keys(&self) -> impl Iterator<Item = (Vec<&Ident>, &References)> {
+        let column_foreign_keys = self.columns.iter().filter_map(|column| {
+            column
+                .constraints()
+                .references()
+                .map(|references| (vec![column.name()], references))
+        });
+        let table_foreign_keys = self.constraints.iter().filter_map(|constraint| {
+            constraint
+                .kind()
+                .foreign_key()
+                .map(|(columns, references)| (columns.iter().collect(), references))
+        });
+        column_foreign_keys.chain(table_foreign_keys)
+    }
+
+    /// Derives the name of the many-to-one relation for a foreign key, `post` for a `post_id`
+    /// column, or the name of the referenced table otherwise.
+    fn foreign_key_relation_name(columns: &[&Ident], references: &References) -> Ident {
+        if let [column] = columns
+            && let Some(name) = column.to_string().strip_suffix("_id")
+            && !name.is_empty()
+        {
+            return Ident::new(name, column.span());
+        }
+        references
+            .table()
+            .segments
+            .last()
+            .expect("paths should have at least one segment")
+            .ident
+            .clone()
+    }
+
+    fn build_foreign_key_relations(&mut self) -> syn::Result<()> {
+        let names = self
+            .foreign_keys()
+            .map(|(columns, references)| Self::foreign_key_relation_name(&columns, references))
+            .collect::<Vec<_>>();
+
+        let mut relations = vec![];
+        for ((columns, references), name) in self.foreign_keys().zip(&names) {
+            // Relations with ambiguous names must be declared explicitly, as must relations that
+            // already are.
+            if names.iter().filter(|other| *other == name).count() > 1
+                || self
+                    .declared_relations()
+                    .any(|relation| relation.name() == name)
+            {
+                continue;
+            }
+
+            let columns = columns.iter().map(|column| {
+                self.columns()
+                    .find(|table_column| table_column.column().name() == *column)
+                    .map(|table_column| table_column.name_or_alias().clone())
+                    .unwrap_or_else(|| (*column).clone())
+            });
+            let table = references.table();
+            let target_columns = references.columns();
+            relations.push(syn::parse2(quote! {
+                #name: (#(#columns),*) => #table (#target_columns)
+            })?);
+        }
+        self.foreign_key_relations = relations;
+        Ok(())
+    }
+
     fn unmatched_column_overrides(&self) -> impl Iterator<Item = &ColumnOverride> {
         self.field_specs
             .iter()
@@ -77,15 +207,156 @@ impl Table {
 impl Parse for Table {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let content;
-        Ok(Self {
-            _create: input.parse()?,
-            _table: input.parse()?,
-            name: input.parse()?,
-            _paren: syn::parenthesized!(content in input),
-            columns: content.parse_terminated(Column::parse, Token![,])?,
-            _semi: input.parse()?,
+        let _create = input.parse()?;
+        let kind: TableKind = input.parse()?;
+        let (schema, name): (_, Ident) = if input.peek2(Token![.]) {
+            (Some((input.parse()?, input.parse()?)), input.parse()?)
+        } else {
+            (None, input.parse()?)
+        };
+        let _paren = syn::parenthesized!(content in input);
+
+        let mut columns: Vec<Column> = vec![];
+        let mut constraints: Vec<TableConstraint> = vec![];
+        while !content.is_empty() {
+            if TableConstraint::peek(&content) {
+                constraints.push(content.parse()?);
+            } else {
+                columns.push(content.parse()?);
+            }
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Token![,]>()?;
+        }
+
+        let table_primary_keys = constraints
+            .iter()
+            .filter_map(|constraint| constraint.kind().primary_key_columns())
+            .collect::<Vec<_>>();
+        let column_primary_keys = columns
+            .iter()
+            .filter(|column| column.constraints().primary_key().is_some())
+            .count();
+        if table_primary_keys.len() + column_primary_keys > 1 {
+            emit_error!(
+                name.span(),
+                "multiple primary keys for table `{}` are not allowed",
+                name
+            );
+        }
+        for primary_key in table_primary_keys {
+            for column in &mut columns {
+                if primary_key.iter().any(|name| name == column.name()) {
+                    column.set_table_primary_key();
+                }
+            }
+        }
+
+        // Views only declare the shape of their rows, the constraints are those of the underlying
+        // tables.
+        if kind.is_view() {
+            if !constraints.is_empty() {
+                emit_error!(name.span(), "view `{}` cannot have table constraints", name);
+            }
+            for column in &columns {
+                let constraints = column.constraints();
+                if constraints.primary_key().is_some()
+                    || constraints.default().is_some()
+                    || constraints.references().is_some()
+                    || constraints.generated().is_some()
+                {
+                    emit_error!(
+                        column.name().span(),
+                        "column `{}` of a view can only be declared `not null`",
+                        column.name()
+                    );
+                }
+            }
+        }
+
+        for column in &columns {
+            if let Some(generated) = column.constraints().generated() {
+                if column.constraints().default().is_some() {
+                    emit_error!(
+                        column.name().span(),
+                        "column `{}` cannot have both a default value and a `generated` clause",
+                        column.name()
+                    );
+                }
+                if generated.is_identity() && !column.data_type().is_integer() {
+                    emit_error!(
+                        column.name().span(),
+                        "identity column `{}` must be of type `smallint`, `int` or `bigint`",
+                        column.name()
+                    );
+                }
+            }
+        }
+
+        for column in &columns {
+            if let Some(references) = column.constraints().references()
+                && references.columns().len() != 1
+            {
+                emit_error!(
+                    references.paren().span.span(),
+                    "column `{}` must reference exactly one column",
+                    column.name()
+                );
+            }
+        }
+
+        let _semi = input.parse()?;
+        let mut indexes = vec![];
+        let mut custom_types = vec![];
+        loop {
+            if Index::peek(input) {
+                indexes.push(input.parse()?);
+            } else if CustomTypeUse::peek(input) {
+                custom_types.push(input.parse()?);
+            } else {
+                break;
+            }
+        }
+        for column in &mut columns {
+            column.resolve_custom_type(&custom_types);
+        }
+
+        let mut result = Self {
+            _create,
+            kind,
+            _paren,
+            schema,
+            name,
+            columns,
+            constraints,
+            _semi,
+            indexes,
             field_specs: input.parse_terminated(FieldSpec::parse, Token![,])?,
-        })
+            foreign_key_relations: vec![],
+        };
+        result.build_foreign_key_relations()?;
+
+        for index in &result.indexes {
+            // The schemas are only compared if both are given, because pg_dump qualifies every
+            // table name.
+            let schema_matches = match (index.table_schema(), &result.schema) {
+                (Some(index_schema), Some((schema, _))) => index_schema == schema,
+                _ => true,
+            };
+            if index.table() != &result.name || !schema_matches {
+                emit_error!(
+                    index.table().span(),
+                    "index must be declared on table `{}`",
+                    result.name
+                );
+            }
+            if matches!(result.kind, TableKind::View(_)) {
+                emit_error!(index.table().span(), "views cannot have indexes");
+            }
+        }
+
+        Ok(result)
     }
 }
 
@@ -93,6 +364,13 @@ impl ToTokens for Table {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let name = &self.name;
         let name_string = name.to_string();
+        let schema = match &self.schema {
+            Some((schema, _)) => {
+                let schema_string = schema.to_string();
+                quote! { Some(#schema_string) }
+            }
+            None => quote! { None },
+        };
 
         for unmatched_column_override in self.unmatched_column_overrides() {
             emit_error!(
@@ -103,12 +381,69 @@ impl ToTokens for Table {
         }
 
         let columns = self.columns().collect::<Vec<_>>();
-        let relations = self.relations().map(|relation| relation.to_token_stream());
+        let relations = self
+            .relations()
+            .map(|relation| relation.to_token_stream(name));
+
+        // Foreign keys are exposed by the name of the table they reference, so that the other
+        // table can declare the inverse relation by name only.
+        let foreign_keys = {
+            let keys = self
+                .foreign_keys()
+                .map(|(columns, references)| {
+                    let target = references
+                        .table()
+                        .segments
+                        .last()
+                        .expect("paths should have at least one segment")
+                        .ident
+                        .clone();
+                    let relation = Self::foreign_key_relation_name(&columns, references);
+                    (target, relation)
+                })
+                .collect::<Vec<_>>();
+            keys.iter()
+                .filter(|(target, _)| keys.iter().filter(|(other, _)| other == target).count() == 1)
+                .filter(|(_, relation)| self.relations().any(|other| other.name() == relation))
+                .map(
+                    |(target, relation)| quote! { pub use super::relations::#relation as #target; },
+                )
+                .collect::<Vec<_>>()
+        };
 
         let column_names = columns
             .iter()
             .map(ColumnWithOverride::name_or_alias)
             .collect::<Vec<_>>();
+        let mut custom_types = columns
+            .iter()
+            .filter_map(ColumnWithOverride::custom_type)
+            .collect::<Vec<_>>();
+        custom_types.sort();
+        custom_types.dedup();
+
+        // Generation expressions refer to columns by their database names, like check constraints.
+        // The module lives outside of `columns` under a reserved name, so that it cannot collide
+        // with a column named `scope`.
+        let generated_scope = columns
+            .iter()
+            .any(|column| {
+                column
+                    .column()
+                    .constraints()
+                    .generated()
+                    .is_some_and(|generated| generated.expr().is_some())
+            })
+            .then(|| {
+                let database_names = columns.iter().map(|column| column.column().name());
+                quote! {
+                    mod __kosame_generated_scope {
+                        pub(super) mod columns {
+                            #(pub(in super::super) use super::super::columns::#column_names as #database_names;)*
+                        }
+                    }
+                }
+            });
         let relation_names = self.relations().map(Relation::name).collect::<Vec<_>>();
 
         let select_struct = RowStruct::new(
@@ -125,6 +460,71 @@ impl ToTokens for Table {
                 .collect(),
         );
 
+        let key_columns = match self
+            .constraints
+            .iter()
+            .find_map(|constraint| constraint.kind().primary_key_columns())
+        {
+            Some(primary_key) => primary_key
+                .iter()
+                .filter_map(|name| columns.iter().find(|column| column.column().name() == name))
+                .collect::<Vec<_>>(),
+            None => columns
+                .iter()
+                .filter(|column| column.column().constraints().primary_key().is_some())
+                .collect::<Vec<_>>(),
+        };
+
+        // Column-level primary keys are exposed as table constraints as well, so that all primary
+        // keys can be found in the same place at runtime.
+        let column_primary_key = columns
+            .iter()
+            .filter(|column| column.column().constraints().primary_key().is_some())
+            .map(ColumnWithOverride::name_or_alias)
+            .collect::<Vec<_>>();
+        let column_primary_key = (!column_primary_key.is_empty()).then(|| {
+            quote! {
+                ::kosame::schema::Constraint::new(
+                    None,
+                    ::kosame::schema::ConstraintKind::PrimaryKey {
+                        columns: &[#(&columns::#column_primary_key::COLUMN),*],
+                    },
+                )
+            }
+        });
+        let column_foreign_keys = columns.iter().filter_map(|column| {
+            let references = column.column().constraints().references()?;
+            let kind = references.to_constraint_kind_tokens(&[column.name_or_alias()]);
+            Some(quote! { ::kosame::schema::Constraint::new(None, #kind) })
+        });
+        let constraints = column_primary_key
+            .into_iter()
+            .chain(column_foreign_keys)
+            .chain(
+                self.constraints
+                    .iter()
+                    .map(|constraint| constraint.to_token_stream(&columns)),
+            );
+        let indexes = self
+            .indexes
+            .iter()
+            .map(|index| index.to_token_stream(&columns));
+        let insert_struct = (!self.kind.is_view()).then(|| InsertStruct::new(&columns));
+        let update_struct = UpdateStruct::new(&columns, key_columns.clone());
+        let by_pk = ByPk::new(name, key_columns);
+        let kind = &self.kind;
+        let refresh = matches!(kind, TableKind::MaterializedView(..)).then(|| {
+            quote! {
+                pub const fn refresh() -> ::kosame::mutation::Refresh {
+                    ::kosame::mutation::Refresh::new(&TABLE, false)
+                }
+
+                pub const fn refresh_concurrently() -> ::kosame::mutation::Refresh {
+                    ::kosame::mutation::Refresh::new(&TABLE, true)
+                }
+            }
+        });
+
         let star_macro = {
             static UNIQUE_ID: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
             let unique_macro_name = quote::format_ident!(
@@ -163,6 +563,10 @@ impl ToTokens for Table {
         quote! {
             // #docs
             pub mod #name {
+                #(use super::#custom_types;)*
+
+                #generated_scope
+
                 pub mod columns {
                     #(#columns)*
                 }
@@ -171,19 +575,32 @@ impl ToTokens for Table {
                     #(#relations)*
                 }
 
+                pub mod foreign_keys {
+                    #(#foreign_keys)*
+                }
+
                 pub mod columns_and_relations {
                     #(pub use super::columns::#column_names;)*
                     #(pub use super::relations::#relation_names;)*
                 }
 
+                pub const SCHEMA: Option<&str> = #schema;
                 pub const NAME: &str = #name_string;
                 pub const TABLE: ::kosame::schema::Table = ::kosame::schema::Table::new(
+                    SCHEMA,
                     #name_string,
+                    #kind,
                     &[#(&columns::#column_names::COLUMN),*],
                     &[#(&relations::#relation_names::RELATION),*],
+                    &[#(#constraints),*],
+                    &[#(#indexes),*],
                 );
 
                 #select_struct
+                #insert_struct
+                #update_struct
+                #by_pk
+                #refresh
 
                 #star_macro
             }
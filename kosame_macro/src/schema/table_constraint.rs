@@ -0,0 +1,271 @@
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+use super::{column_constraint::References, column_override::ColumnWithOverride};
+use crate::{
+    expr::{CorrelationCheck, Expr},
+    query::bind_params::BindParamsBuilder,
+};
+
+mod kw {
+    syn::custom_keyword!(constraint);
+
+    syn::custom_keyword!(primary);
+    syn::custom_keyword!(key);
+
+    syn::custom_keyword!(unique);
+    syn::custom_keyword!(check);
+
+    syn::custom_keyword!(foreign);
+}
+
+pub struct TableConstraint {
+    name: Option<(kw::constraint, Ident)>,
+    kind: TableConstraintKind,
+}
+
+impl TableConstraint {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(kw::constraint)
+            || (input.peek(kw::primary) && input.peek2(kw::key))
+            || (input.peek(kw::unique) && input.peek2(syn::token::Paren))
+            || (input.peek(kw::check) && input.peek2(syn::token::Paren))
+            || (input.peek(kw::foreign) && input.peek2(kw::key))
+    }
+
+    pub fn kind(&self) -> &TableConstraintKind {
+        &self.kind
+    }
+
+    pub fn to_token_stream(&self, table_columns: &[ColumnWithOverride]) -> TokenStream {
+        let name = match &self.name {
+            Some((_, name)) => {
+                let name = name.to_string();
+                quote! { Some(#name) }
+            }
+            None => quote! { None },
+        };
+
+        let kind = match &self.kind {
+            TableConstraintKind::PrimaryKey { columns, .. } => {
+                let columns = resolve_columns(columns, table_columns);
+                quote! {
+                    ::kosame::schema::ConstraintKind::PrimaryKey {
+                        columns: &[#(&columns::#columns::COLUMN),*],
+                    }
+                }
+            }
+            TableConstraintKind::Unique { columns, .. } => {
+                let columns = resolve_columns(columns, table_columns);
+                quote! {
+                    ::kosame::schema::ConstraintKind::Unique {
+                        columns: &[#(&columns::#columns::COLUMN),*],
+                    }
+                }
+            }
+            TableConstraintKind::Check { expr, .. } => {
+                // Like the rest of the table definition, check constraints refer to columns by their
+                // database names rather than their aliases.
+                let column_names = table_columns.iter().map(|column| column.column().name());
+                let aliases = table_columns.iter().map(ColumnWithOverride::name_or_alias);
+                quote! {
+                    ::kosame::schema::ConstraintKind::Check {
+                        expr: {
+                            mod scope {
+                                pub(super) mod columns {
+                                    #(pub(in super::super) use super::super::columns::#aliases as #column_names;)*
+                                }
+                            }
+                            #expr
+                        },
+                    }
+                }
+            }
+            TableConstraintKind::ForeignKey {
+                columns,
+                references,
+                ..
+            } => {
+                let columns = resolve_columns(columns, table_columns);
+                references.to_constraint_kind_tokens(&columns)
+            }
+        };
+
+        quote! {
+            ::kosame::schema::Constraint::new(#name, #kind)
+        }
+    }
+}
+
+impl Parse for TableConstraint {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = if input.peek(kw::constraint) {
+            Some((input.parse()?, input.parse()?))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            name,
+            kind: input.parse()?,
+        })
+    }
+}
+
+fn resolve_columns<'a>(
+    columns: &'a Punctuated<Ident, Token![,]>,
+    table_columns: &'a [ColumnWithOverride],
+) -> Vec<&'a Ident> {
+    columns
+        .iter()
+        .filter_map(|column| {
+            let resolved = table_columns
+                .iter()
+                .find(|table_column| table_column.column().name() == column)
+                .map(ColumnWithOverride::name_or_alias);
+            if resolved.is_none() {
+                emit_error!(
+                    column.span(),
+                    "constraint column `{}` does not match any column name",
+                    column
+                );
+            }
+            resolved
+        })
+        .collect()
+}
+
+#[allow(unused)]
+pub enum TableConstraintKind {
+    PrimaryKey {
+        _primary: kw::primary,
+        _key: kw::key,
+        _paren: syn::token::Paren,
+        columns: Punctuated<Ident, Token![,]>,
+    },
+    Unique {
+        _unique: kw::unique,
+        _paren: syn::token::Paren,
+        columns: Punctuated<Ident, Token![,]>,
+    },
+    Check {
+        _check: kw::check,
+        _paren: syn::token::Paren,
+        expr: Expr,
+    },
+    ForeignKey {
+        _foreign: kw::foreign,
+        _key: kw::key,
+        _paren: syn::token::Paren,
+        columns: Punctuated<Ident, Token![,]>,
+        references: References,
+    },
+}
+
+impl TableConstraintKind {
+    pub fn primary_key_columns(&self) -> Option<&Punctuated<Ident, Token![,]>> {
+        match self {
+            Self::PrimaryKey { columns, .. } => Some(columns),
+            _ => None,
+        }
+    }
+
+    pub fn foreign_key(&self) -> Option<(&Punctuated<Ident, Token![,]>, &References)> {
+        match self {
+            Self::ForeignKey {
+                columns,
+                references,
+                ..
+            } => Some((columns, references)),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for TableConstraintKind {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::primary) {
+            let _primary = input.parse()?;
+            let _key = input.parse()?;
+            let _paren = parenthesized!(content in input);
+            let columns = content.parse_terminated(Ident::parse, Token![,])?;
+            check_columns_not_empty(&_paren, &columns);
+            Ok(Self::PrimaryKey {
+                _primary,
+                _key,
+                _paren,
+                columns,
+            })
+        } else if lookahead.peek(kw::unique) {
+            let _unique = input.parse()?;
+            let _paren = parenthesized!(content in input);
+            let columns = content.parse_terminated(Ident::parse, Token![,])?;
+            check_columns_not_empty(&_paren, &columns);
+            Ok(Self::Unique {
+                _unique,
+                _paren,
+                columns,
+            })
+        } else if lookahead.peek(kw::check) {
+            let _check = input.parse()?;
+            let _paren = parenthesized!(content in input);
+            let expr: Expr = content.parse()?;
+            expr.accept(&mut CorrelationCheck::new(vec![]));
+
+            let mut builder = BindParamsBuilder::new();
+            expr.accept(&mut builder);
+            if !builder.build().is_empty() {
+                emit_error!(
+                    _paren.span.span(),
+                    "bind parameters are not allowed in check constraints"
+                );
+            }
+
+            Ok(Self::Check {
+                _check,
+                _paren,
+                expr,
+            })
+        } else if lookahead.peek(kw::foreign) {
+            let _foreign = input.parse()?;
+            let _key = input.parse()?;
+            let _paren = parenthesized!(content in input);
+            let columns = content.parse_terminated(Ident::parse, Token![,])?;
+            check_columns_not_empty(&_paren, &columns);
+            let references: References = input.parse()?;
+            if references.columns().len() != columns.len() {
+                emit_error!(
+                    references.paren().span.span(),
+                    "number of columns must match on both sides of the foreign key"
+                );
+            }
+            Ok(Self::ForeignKey {
+                _foreign,
+                _key,
+                _paren,
+                columns,
+                references,
+            })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+fn check_columns_not_empty(paren: &syn::token::Paren, columns: &Punctuated<Ident, Token![,]>) {
+    if columns.is_empty() {
+        emit_error!(
+            paren.span.span(),
+            "at least one column must be specified for constraint"
+        );
+    }
+}